@@ -1,15 +1,510 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Context;
+use secrecy::{ExposeSecret, Secret};
 use serde::Deserialize;
-use zksync_basic_types::secrets::PrivateKey;
+use zksync_basic_types::{
+    secrets::{APIKey, PrivateKey},
+    url::SensitiveUrl,
+};
+
+pub const DEFAULT_MAX_SUBMIT_RETRIES: u32 = 3;
+pub const DEFAULT_SUBMIT_RETRY_BACKOFF_MS: u64 = 1000;
+pub const DEFAULT_BALANCE_POLL_INTERVAL_MS: u64 = 60_000;
+pub const DEFAULT_BALANCE_CACHE_TTL_MS: u64 = 5_000;
+pub const DEFAULT_MAX_LIST_BLOBS_RANGE: u64 = 100;
+pub const DEFAULT_GAS_PRICE_MULTIPLIER: f64 = 1.0;
+pub const DEFAULT_GAS_PRICE_ESCALATION_FACTOR: f64 = 1.0;
+pub const DEFAULT_MAX_GAS_PRICE_MULTIPLIER: f64 = 3.0;
+pub const DEFAULT_HEIGHT_WAIT_TIMEOUT_MS: u64 = 120_000;
+pub const DEFAULT_INCLUSION_POLL_CONCURRENCY: usize = 8;
+/// The largest blob that fits into a single Celestia transaction (2 MiB) together with the transaction envelope.
+pub const MAX_BLOB_SIZE: usize = 1_973_786;
+
+/// Maximum size of a version 0 namespace ID, not counting the leading zero bytes.
+const NAMESPACE_V0_ID_SIZE: usize = 10;
 
 #[derive(Clone, Debug, Default, PartialEq, Deserialize)]
 pub struct CelestiaConfig {
     pub api_node_url: String,
+    /// Single namespace to post blobs to. Kept for backward compatibility, ignored if `namespaces` is set.
+    #[serde(default)]
     pub namespace: String,
+    /// Namespaces to post blobs to. Blobs are posted to the first one unless another one is selected explicitly.
+    #[serde(default)]
+    pub namespaces: Vec<String>,
     pub chain_id: String,
+    /// Default timeout for the gRPC connection and requests, used if the more specific timeouts are not set.
     pub timeout_ms: u64,
+    /// Timeout for establishing the gRPC connection to the node.
+    pub connect_timeout_ms: Option<u64>,
+    /// Timeout for each gRPC request made while submitting blobs.
+    pub submit_timeout_ms: Option<u64>,
+    /// The maximum size of a blob in bytes, at most [`MAX_BLOB_SIZE`]. If not set, [`MAX_BLOB_SIZE`] is used.
+    pub max_blob_size: Option<usize>,
+    /// The maximum number of times a blob submission is retried after a transient failure.
+    pub max_submit_retries: Option<u32>,
+    /// The delay before the first submission retry, doubled after each attempt.
+    pub submit_retry_backoff_ms: Option<u64>,
+    /// URL of the celestia-node JSON-RPC API, used to read submitted blobs back.
+    pub node_rpc_url: Option<String>,
+    /// Whether payloads larger than the blob size limit are split into several blobs submitted in one transaction.
+    /// Payloads are still limited by the transaction size, so this only helps if `max_blob_size` is small.
+    #[serde(default)]
+    pub allow_blob_chunking: bool,
+    /// If set, the account balance is polled in the background and a warning is logged once it drops below
+    /// this value, in `utia`. Requires `node_rpc_url` to be configured.
+    pub balance_warn_threshold: Option<u64>,
+    /// The interval between account balance checks.
+    pub balance_poll_interval_ms: Option<u64>,
+    /// Path to a PEM-encoded CA certificate used to verify the gRPC endpoint, e.g. one behind a TLS-terminating proxy.
+    pub tls_ca_cert_path: Option<String>,
+    /// Path to a PEM-encoded client certificate presented to the gRPC endpoint. Requires `tls_client_key_path`.
+    pub tls_client_cert_path: Option<String>,
+    /// Path to the PEM-encoded private key of the client certificate. Requires `tls_client_cert_path`.
+    pub tls_client_key_path: Option<String>,
+    /// The number of blocks that must be built on top of the blob's block before its inclusion is reported.
+    /// Zero means inclusion is reported as soon as the blob is included.
+    #[serde(default)]
+    pub confirmation_depth: u64,
+    /// If set, a blob that isn't included within this time since it was first polled is reported as failed.
+    pub max_inclusion_wait_ms: Option<u64>,
+    /// For how long a fetched account balance is reused by `balance()` before it's fetched again.
+    pub balance_cache_ttl_ms: Option<u64>,
+    /// The maximum number of heights that can be queried by a single `list_blobs()` call.
+    pub max_list_blobs_range: Option<u64>,
+    /// The multiplier applied to the minimum gas price of the node when paying for blobs.
+    pub gas_price_multiplier: Option<f64>,
+    /// The factor the gas price multiplier is multiplied by on each submission retry, so that a transaction stuck
+    /// in the mempool because of congestion is resubmitted with a higher fee.
+    pub gas_price_escalation_factor: Option<f64>,
+    /// The cap on the escalated gas price multiplier.
+    pub max_gas_price_multiplier: Option<f64>,
+    /// For how long `dispatch_blob_after_height()` waits for the chain to reach the requested height.
+    pub height_wait_timeout_ms: Option<u64>,
+    /// If set, dispatching the same data to the same namespace again within this time returns the blob ID
+    /// of the previous submission instead of submitting the data again. Disabled by default.
+    pub dispatch_dedup_ttl_ms: Option<u64>,
+    /// If set, the blob ID of each successful submission is appended to this file, so that submissions
+    /// can be recovered after a crash with `recover_pending()`.
+    pub submission_log_path: Option<String>,
+    /// The maximum number of blobs polled for inclusion concurrently when several blobs are polled at once.
+    pub inclusion_poll_concurrency: Option<usize>,
+    /// Bech32 address of an account that granted a fee allowance to the posting account. If set, transaction fees
+    /// are paid by this account, so the posting account doesn't need to hold funds.
+    pub fee_granter: Option<String>,
+}
+
+impl CelestiaConfig {
+    /// Returns the configured namespaces, falling back to the single `namespace` if `namespaces` is empty.
+    pub fn namespaces(&self) -> Vec<String> {
+        if self.namespaces.is_empty() {
+            vec![self.namespace.clone()]
+        } else {
+            self.namespaces.clone()
+        }
+    }
+
+    /// Checks the whole config, so that all problems are reported at once on startup instead of one by one
+    /// when the client is created or used.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        let mut problems = vec![];
+
+        if let Err(err) = check_url(&self.api_node_url) {
+            problems.push(format!("api_node_url is invalid: {err}"));
+        }
+        if let Some(Err(err)) = self.node_rpc_url.as_deref().map(check_url) {
+            problems.push(format!("node_rpc_url is invalid: {err}"));
+        }
+        if self.chain_id.is_empty() {
+            problems.push("chain_id is not set".to_owned());
+        }
+
+        let namespaces = self.namespaces();
+        let mut namespace_ids = Vec::with_capacity(namespaces.len());
+        for (idx, namespace) in namespaces.iter().enumerate() {
+            // An empty namespace would be decoded as the all-zero namespace, so it's rejected explicitly.
+            if namespace.is_empty() {
+                problems.push(if self.namespaces.is_empty() {
+                    "neither namespace nor namespaces is set".to_owned()
+                } else {
+                    format!("namespace at index {idx} is empty")
+                });
+                continue;
+            }
+            match namespace_id(namespace) {
+                Ok(id) => {
+                    if let Some(prev_idx) = namespace_ids.iter().position(|prev| *prev == id) {
+                        problems.push(format!(
+                            "namespace `{namespace}` at index {idx} duplicates the one at index {prev_idx}"
+                        ));
+                    }
+                    namespace_ids.push(id);
+                }
+                Err(err) => problems.push(format!("namespace `{namespace}` is invalid: {err}")),
+            }
+        }
+
+        let positive_values = [
+            ("timeout_ms", Some(self.timeout_ms)),
+            ("connect_timeout_ms", self.connect_timeout_ms),
+            ("submit_timeout_ms", self.submit_timeout_ms),
+            ("balance_poll_interval_ms", self.balance_poll_interval_ms),
+            ("height_wait_timeout_ms", self.height_wait_timeout_ms),
+            ("max_list_blobs_range", self.max_list_blobs_range),
+            ("max_blob_size", self.max_blob_size.map(|size| size as u64)),
+        ];
+        for (name, value) in positive_values {
+            if value == Some(0) {
+                problems.push(format!("{name} must be positive"));
+            }
+        }
+        if let Some(max_blob_size) = self.max_blob_size.filter(|&size| size > MAX_BLOB_SIZE) {
+            problems.push(format!(
+                "max_blob_size must be at most {MAX_BLOB_SIZE} bytes to fit into a transaction, got {max_blob_size}"
+            ));
+        }
+
+        let multiplier = self.gas_price_multiplier();
+        if !(multiplier.is_finite() && multiplier > 0.0) {
+            problems.push(format!(
+                "gas_price_multiplier must be positive, got {multiplier}"
+            ));
+        }
+        let escalation_factor = self.gas_price_escalation_factor();
+        if !(escalation_factor.is_finite() && escalation_factor >= 1.0) {
+            problems.push(format!(
+                "gas_price_escalation_factor must be at least 1, got {escalation_factor}"
+            ));
+        }
+        let max_multiplier = self.max_gas_price_multiplier();
+        if !(max_multiplier.is_finite() && max_multiplier >= multiplier) {
+            problems.push(format!(
+                "max_gas_price_multiplier must be at least gas_price_multiplier ({multiplier}), got {max_multiplier}"
+            ));
+        }
+
+        if self.balance_warn_threshold.is_some() && self.node_rpc_url.is_none() {
+            problems.push("balance_warn_threshold requires node_rpc_url to be set".to_owned());
+        }
+        if self.tls_client_cert_path.is_some() != self.tls_client_key_path.is_some() {
+            problems.push(
+                "tls_client_cert_path and tls_client_key_path must be set together".to_owned(),
+            );
+        }
+        let tls_paths = [
+            ("tls_ca_cert_path", &self.tls_ca_cert_path),
+            ("tls_client_cert_path", &self.tls_client_cert_path),
+            ("tls_client_key_path", &self.tls_client_key_path),
+        ];
+        for (name, path) in tls_paths {
+            if let Some(path) = path.as_deref().filter(|path| !Path::new(path).is_file()) {
+                problems.push(format!("{name} `{path}` is not a file"));
+            }
+        }
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+        let problems: Vec<_> = problems
+            .iter()
+            .map(|problem| format!("- {problem}"))
+            .collect();
+        anyhow::bail!("invalid Celestia config:\n{}", problems.join("\n"))
+    }
+
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_millis(self.connect_timeout_ms.unwrap_or(self.timeout_ms))
+    }
+
+    pub fn submit_timeout(&self) -> Duration {
+        Duration::from_millis(self.submit_timeout_ms.unwrap_or(self.timeout_ms))
+    }
+
+    pub fn balance_poll_interval(&self) -> Duration {
+        Duration::from_millis(
+            self.balance_poll_interval_ms
+                .unwrap_or(DEFAULT_BALANCE_POLL_INTERVAL_MS),
+        )
+    }
+
+    pub fn balance_cache_ttl(&self) -> Duration {
+        Duration::from_millis(
+            self.balance_cache_ttl_ms
+                .unwrap_or(DEFAULT_BALANCE_CACHE_TTL_MS),
+        )
+    }
+
+    pub fn max_list_blobs_range(&self) -> u64 {
+        self.max_list_blobs_range
+            .unwrap_or(DEFAULT_MAX_LIST_BLOBS_RANGE)
+    }
+
+    pub fn gas_price_multiplier(&self) -> f64 {
+        self.gas_price_multiplier
+            .unwrap_or(DEFAULT_GAS_PRICE_MULTIPLIER)
+    }
+
+    pub fn gas_price_escalation_factor(&self) -> f64 {
+        self.gas_price_escalation_factor
+            .unwrap_or(DEFAULT_GAS_PRICE_ESCALATION_FACTOR)
+    }
+
+    pub fn max_gas_price_multiplier(&self) -> f64 {
+        self.max_gas_price_multiplier
+            .unwrap_or(DEFAULT_MAX_GAS_PRICE_MULTIPLIER)
+    }
+
+    pub fn height_wait_timeout(&self) -> Duration {
+        Duration::from_millis(
+            self.height_wait_timeout_ms
+                .unwrap_or(DEFAULT_HEIGHT_WAIT_TIMEOUT_MS),
+        )
+    }
+
+    pub fn inclusion_poll_concurrency(&self) -> usize {
+        self.inclusion_poll_concurrency
+            .unwrap_or(DEFAULT_INCLUSION_POLL_CONCURRENCY)
+            .max(1)
+    }
+
+    pub fn max_inclusion_wait(&self) -> Option<Duration> {
+        self.max_inclusion_wait_ms.map(Duration::from_millis)
+    }
+
+    pub fn dispatch_dedup_ttl(&self) -> Option<Duration> {
+        self.dispatch_dedup_ttl_ms.map(Duration::from_millis)
+    }
+
+    pub fn max_submit_retries(&self) -> u32 {
+        self.max_submit_retries
+            .unwrap_or(DEFAULT_MAX_SUBMIT_RETRIES)
+    }
+
+    pub fn submit_retry_backoff(&self) -> Duration {
+        Duration::from_millis(
+            self.submit_retry_backoff_ms
+                .unwrap_or(DEFAULT_SUBMIT_RETRY_BACKOFF_MS),
+        )
+    }
+}
+
+fn check_url(url: &str) -> anyhow::Result<()> {
+    // The error doesn't include the URL, since it may contain credentials.
+    let url: SensitiveUrl = url.parse()?;
+    let scheme = url.expose_url().scheme();
+    anyhow::ensure!(
+        matches!(scheme, "http" | "https"),
+        "unsupported scheme `{scheme}`"
+    );
+    Ok(())
+}
+
+/// Returns the namespace ID without the leading zero bytes, which are a part of the full namespace encoding.
+fn namespace_id(namespace: &str) -> anyhow::Result<Vec<u8>> {
+    let bytes = hex::decode(namespace).context("not valid hex")?;
+    let id: Vec<u8> = bytes.into_iter().skip_while(|&byte| byte == 0).collect();
+    anyhow::ensure!(
+        id.len() <= NAMESPACE_V0_ID_SIZE,
+        "must be at most {NAMESPACE_V0_ID_SIZE} bytes for version 0, got {} bytes",
+        id.len()
+    );
+    Ok(id)
 }
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct CelestiaSecrets {
-    pub private_key: PrivateKey,
+    pub private_key: CelestiaPrivateKey,
+    /// Auth token for the celestia-node JSON-RPC API.
+    pub node_auth_token: Option<APIKey>,
+}
+
+/// Source of the private key of the account paying for the blobs.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CelestiaPrivateKey {
+    /// The hex-encoded key itself.
+    Inline(PrivateKey),
+    /// Path to a file containing the hex-encoded key.
+    File(PathBuf),
+    /// Name of an environment variable containing the hex-encoded key.
+    Env(String),
+}
+
+impl CelestiaPrivateKey {
+    /// Selects the source of the key, checking that exactly one of them is provided.
+    pub fn from_sources(
+        inline: Option<PrivateKey>,
+        file: Option<PathBuf>,
+        env: Option<String>,
+    ) -> anyhow::Result<Self> {
+        match (inline, file, env) {
+            (Some(key), None, None) => Ok(Self::Inline(key)),
+            (None, Some(path), None) => Ok(Self::File(path)),
+            (None, None, Some(var)) => Ok(Self::Env(var)),
+            (None, None, None) => anyhow::bail!(
+                "Celestia private key is not provided, set one of private_key, private_key_file or private_key_env"
+            ),
+            _ => anyhow::bail!(
+                "only one of private_key, private_key_file or private_key_env can be set for Celestia"
+            ),
+        }
+    }
+
+    /// Reads the key from its source. The intermediate copies of the key are zeroized when dropped.
+    pub fn resolve(&self) -> anyhow::Result<PrivateKey> {
+        let raw = match self {
+            Self::Inline(key) => return Ok(key.clone()),
+            Self::File(path) => Secret::new(std::fs::read_to_string(path).with_context(|| {
+                format!(
+                    "failed to read Celestia private key from `{}`",
+                    path.display()
+                )
+            })?),
+            Self::Env(var) => Secret::new(std::env::var(var).with_context(|| {
+                format!("failed to read Celestia private key from env variable `{var}`")
+            })?),
+        };
+        let key = raw.expose_secret().trim();
+        anyhow::ensure!(!key.is_empty(), "Celestia private key is empty");
+        Ok(PrivateKey(Secret::new(key.to_owned())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_config() -> CelestiaConfig {
+        CelestiaConfig {
+            api_node_url: "http://localhost:9090".to_owned(),
+            namespace: "deadbeef".to_owned(),
+            chain_id: "mocha-4".to_owned(),
+            timeout_ms: 30_000,
+            ..CelestiaConfig::default()
+        }
+    }
+
+    #[test]
+    fn valid_config_passes() {
+        valid_config().validate().unwrap();
+        CelestiaConfig {
+            namespaces: vec!["deadbeef".to_owned(), "00cafe".to_owned()],
+            node_rpc_url: Some("https://localhost:26658".to_owned()),
+            balance_warn_threshold: Some(1_000),
+            ..valid_config()
+        }
+        .validate()
+        .unwrap();
+    }
+
+    #[test]
+    fn all_problems_are_reported() {
+        let config = CelestiaConfig {
+            api_node_url: "localhost:9090".to_owned(),
+            node_rpc_url: Some("not a url".to_owned()),
+            chain_id: String::new(),
+            timeout_ms: 0,
+            ..valid_config()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.starts_with("invalid Celestia config:\n"), "{err}");
+        assert!(
+            err.contains("- api_node_url is invalid: unsupported scheme `localhost`"),
+            "{err}"
+        );
+        assert!(err.contains("- node_rpc_url is invalid: "), "{err}");
+        assert!(err.contains("- chain_id is not set"), "{err}");
+        assert!(err.contains("- timeout_ms must be positive"), "{err}");
+        assert_eq!(err.lines().count(), 5, "{err}");
+    }
+
+    #[test]
+    fn invalid_namespaces_are_reported() {
+        let config = CelestiaConfig {
+            namespaces: ["deadbeef", "xyz", "0000deadbeef", &"ab".repeat(11)]
+                .map(str::to_owned)
+                .to_vec(),
+            ..valid_config()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("- namespace `xyz` is invalid: not valid hex"),
+            "{err}"
+        );
+        assert!(
+            err.contains("- namespace `0000deadbeef` at index 2 duplicates the one at index 0"),
+            "{err}"
+        );
+        assert!(
+            err.contains("must be at most 10 bytes for version 0, got 11 bytes"),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn empty_namespaces_are_reported() {
+        let config = CelestiaConfig {
+            namespace: String::new(),
+            ..valid_config()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("- neither namespace nor namespaces is set"),
+            "{err}"
+        );
+
+        let config = CelestiaConfig {
+            namespaces: vec!["deadbeef".to_owned(), String::new()],
+            ..valid_config()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("- namespace at index 1 is empty"), "{err}");
+    }
+
+    #[test]
+    fn inconsistent_settings_are_reported() {
+        let config = CelestiaConfig {
+            gas_price_multiplier: Some(2.0),
+            gas_price_escalation_factor: Some(0.5),
+            max_gas_price_multiplier: Some(1.5),
+            balance_warn_threshold: Some(1_000),
+            tls_client_cert_path: Some("/nonexistent/client.pem".to_owned()),
+            max_blob_size: Some(0),
+            ..valid_config()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        for problem in [
+            "gas_price_escalation_factor must be at least 1, got 0.5",
+            "max_gas_price_multiplier must be at least gas_price_multiplier (2), got 1.5",
+            "balance_warn_threshold requires node_rpc_url to be set",
+            "tls_client_cert_path and tls_client_key_path must be set together",
+            "tls_client_cert_path `/nonexistent/client.pem` is not a file",
+            "max_blob_size must be positive",
+        ] {
+            assert!(err.contains(problem), "{problem}: {err}");
+        }
+    }
+
+    #[test]
+    fn max_blob_size_is_capped() {
+        CelestiaConfig {
+            max_blob_size: Some(MAX_BLOB_SIZE),
+            ..valid_config()
+        }
+        .validate()
+        .unwrap();
+
+        let config = CelestiaConfig {
+            max_blob_size: Some(MAX_BLOB_SIZE + 1),
+            ..valid_config()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(
+            err.contains("- max_blob_size must be at most 1973786 bytes to fit into a transaction, got 1973787"),
+            "{err}"
+        );
+    }
 }
@@ -19,6 +19,9 @@ pub struct DADispatcherConfig {
     // TODO: run a verification task to check if the L1 contract expects the inclusion proofs to
     // avoid the scenario where contracts expect real proofs, and server is using dummy proofs.
     pub use_dummy_inclusion_data: Option<bool>,
+    /// The maximum time to keep retrying the dispatch of a blob since its first attempt. After that,
+    /// retriable errors are treated as fatal. If not set, retries are only limited by `max_retries`.
+    pub max_retry_age_ms: Option<u64>,
 }
 
 impl DADispatcherConfig {
@@ -28,6 +31,7 @@ impl DADispatcherConfig {
             max_rows_to_dispatch: Some(DEFAULT_MAX_ROWS_TO_DISPATCH),
             max_retries: Some(DEFAULT_MAX_RETRIES),
             use_dummy_inclusion_data: Some(DEFAULT_USE_DUMMY_INCLUSION_DATA),
+            max_retry_age_ms: None,
         }
     }
 
@@ -51,4 +55,8 @@ impl DADispatcherConfig {
         self.use_dummy_inclusion_data
             .unwrap_or(DEFAULT_USE_DUMMY_INCLUSION_DATA)
     }
+
+    pub fn max_retry_age(&self) -> Option<Duration> {
+        self.max_retry_age_ms.map(Duration::from_millis)
+    }
 }
@@ -3,7 +3,7 @@ pub mod types;
 use std::fmt;
 
 use async_trait::async_trait;
-use types::{DAError, DispatchResponse, InclusionData};
+use types::{DAError, DispatchResponse, InclusionData, InclusionStatus};
 
 /// Trait that defines the interface for the data availability layer clients.
 #[async_trait]
@@ -18,6 +18,45 @@ pub trait DataAvailabilityClient: Sync + Send + fmt::Debug {
     /// Fetches the inclusion data for a given blob_id.
     async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError>;
 
+    /// Fetches the inclusion status of a given blob_id. Unlike [`Self::get_inclusion_data()`], distinguishes
+    /// blobs that will never be included from the ones that are still pending.
+    ///
+    /// The default implementation is based on [`Self::get_inclusion_data()`], treating non-retriable errors
+    /// as failed inclusion. Clients that implement this method natively can implement
+    /// [`Self::get_inclusion_data()`] with [`InclusionStatus::into_inclusion_data()`].
+    async fn get_inclusion_status(&self, blob_id: &str) -> Result<InclusionStatus, DAError> {
+        match self.get_inclusion_data(blob_id).await {
+            Ok(Some(data)) => Ok(InclusionStatus::Included(data)),
+            Ok(None) => Ok(InclusionStatus::Pending),
+            Err(err) if !err.is_retriable => {
+                Ok(InclusionStatus::Failed(format!("{:#}", err.error)))
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Fetches the inclusion data for several blobs, returning the results in the order of `blob_ids`.
+    /// The default implementation queries the blobs one by one; clients can override it to query them concurrently.
+    async fn get_inclusion_data_batch(
+        &self,
+        blob_ids: &[String],
+    ) -> Result<Vec<Option<InclusionData>>, DAError> {
+        let mut inclusion_data = Vec::with_capacity(blob_ids.len());
+        for blob_id in blob_ids {
+            inclusion_data.push(self.get_inclusion_data(blob_id).await?);
+        }
+        Ok(inclusion_data)
+    }
+
+    /// Estimates the fee (in the native token of the DA layer) for dispatching a blob of `data_len` bytes
+    /// at the current gas price. Clients that can't estimate the cost return a non-retriable error.
+    async fn estimate_dispatch_cost(&self, _data_len: usize) -> Result<u64, DAError> {
+        Err(DAError {
+            error: anyhow::anyhow!("dispatch cost estimation is not supported by this client"),
+            is_retriable: false,
+        })
+    }
+
     /// Clones the client and wraps it in a Box.
     fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient>;
 
@@ -48,3 +48,29 @@ pub struct InclusionData {
     /// the deserialization of the data in Solidity contracts.
     pub data: Vec<u8>,
 }
+
+/// Inclusion status of a dispatched blob.
+pub enum InclusionStatus {
+    /// The blob is not included yet, or its inclusion is not final yet.
+    Pending,
+    /// The blob is included, with the data needed to verify the inclusion on L1.
+    Included(InclusionData),
+    /// The blob will never be included, e.g. because the blob ID is invalid or the DA layer dropped it.
+    /// It should be dispatched again.
+    Failed(String),
+}
+
+impl InclusionStatus {
+    /// Converts the status into the result of [`get_inclusion_data()`](crate::DataAvailabilityClient::get_inclusion_data),
+    /// reporting a failed blob as a non-retriable error.
+    pub fn into_inclusion_data(self) -> Result<Option<InclusionData>, DAError> {
+        match self {
+            Self::Pending => Ok(None),
+            Self::Included(data) => Ok(Some(data)),
+            Self::Failed(reason) => Err(DAError {
+                error: anyhow::anyhow!("blob inclusion failed: {reason}"),
+                is_retriable: false,
+            }),
+        }
+    }
+}
@@ -5,7 +5,7 @@ use zksync_config::configs::{
         avail::{
             AvailClientConfig, AvailSecrets, AVAIL_FULL_CLIENT_NAME, AVAIL_GAS_RELAY_CLIENT_NAME,
         },
-        celestia::CelestiaSecrets,
+        celestia::{CelestiaPrivateKey, CelestiaSecrets},
         eigen::EigenSecrets,
         DAClientConfig, AVAIL_CLIENT_CONFIG_NAME, CELESTIA_CLIENT_CONFIG_NAME,
         EIGEN_CLIENT_CONFIG_NAME, OBJECT_STORE_CLIENT_CONFIG_NAME,
@@ -67,11 +67,25 @@ impl FromEnv for DataAvailabilitySecrets {
                 })
             }
             CELESTIA_CLIENT_CONFIG_NAME => {
-                let private_key = env::var("DA_SECRETS_PRIVATE_KEY")
-                    .map_err(|e| anyhow::format_err!("Celestia private key not found: {}", e))?
-                    .parse()
-                    .map_err(|e| anyhow::format_err!("failed to parse the private key: {}", e))?;
-                Self::Celestia(CelestiaSecrets { private_key })
+                let private_key = CelestiaPrivateKey::from_sources(
+                    env::var("DA_SECRETS_PRIVATE_KEY")
+                        .ok()
+                        .map(|s| s.parse())
+                        .transpose()
+                        .map_err(|e| {
+                            anyhow::format_err!("failed to parse the private key: {}", e)
+                        })?,
+                    env::var("DA_SECRETS_PRIVATE_KEY_FILE").ok().map(Into::into),
+                    env::var("DA_SECRETS_PRIVATE_KEY_ENV").ok(),
+                )?;
+                let node_auth_token = env::var("DA_SECRETS_NODE_AUTH_TOKEN")
+                    .ok()
+                    .map(|s| s.parse())
+                    .transpose()?;
+                Self::Celestia(CelestiaSecrets {
+                    private_key,
+                    node_auth_token,
+                })
             }
             EIGEN_CLIENT_CONFIG_NAME => {
                 let private_key = env::var("DA_SECRETS_PRIVATE_KEY")
@@ -214,8 +228,33 @@ mod tests {
         DAClientConfig::Celestia(CelestiaConfig {
             api_node_url: api_node_url.to_string(),
             namespace: namespace.to_string(),
+            namespaces: vec![],
             chain_id: chain_id.to_string(),
             timeout_ms,
+            connect_timeout_ms: None,
+            submit_timeout_ms: None,
+            max_blob_size: Some(1_000_000),
+            max_submit_retries: None,
+            submit_retry_backoff_ms: None,
+            node_rpc_url: None,
+            allow_blob_chunking: false,
+            balance_warn_threshold: None,
+            balance_poll_interval_ms: None,
+            tls_ca_cert_path: None,
+            tls_client_cert_path: None,
+            tls_client_key_path: None,
+            confirmation_depth: 0,
+            max_inclusion_wait_ms: None,
+            balance_cache_ttl_ms: None,
+            max_list_blobs_range: None,
+            gas_price_multiplier: None,
+            gas_price_escalation_factor: None,
+            max_gas_price_multiplier: None,
+            height_wait_timeout_ms: None,
+            dispatch_dedup_ttl_ms: None,
+            submission_log_path: None,
+            inclusion_poll_concurrency: None,
+            fee_granter: None,
         })
     }
 
@@ -228,6 +267,7 @@ mod tests {
             DA_NAMESPACE="0x1234567890abcdef"
             DA_CHAIN_ID="mocha-4"
             DA_TIMEOUT_MS="7000"
+            DA_MAX_BLOB_SIZE="1000000"
         "#;
         lock.set_env(config);
 
@@ -280,9 +320,11 @@ mod tests {
         };
         assert_eq!(
             actual.private_key,
-            "f55baf7c0e4e33b1d78fbf52f069c426bc36cff1aceb9bc8f45d14c07f034d73"
-                .parse()
-                .unwrap()
+            CelestiaPrivateKey::Inline(
+                "f55baf7c0e4e33b1d78fbf52f069c426bc36cff1aceb9bc8f45d14c07f034d73"
+                    .parse()
+                    .unwrap()
+            )
         );
     }
 }
@@ -0,0 +1,51 @@
+//! Benchmarks for compressing factory dependencies of a transaction.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use zksync_multivm::utils::compress_bytecodes;
+
+const BYTECODE_COUNT: usize = 50;
+
+/// Generates bytecodes of 10-40 KB each. Chunks are drawn from a limited set, so that bytecodes have
+/// repeated chunks like real contracts do.
+fn generate_bytecodes() -> Vec<Vec<u8>> {
+    let mut rng = StdRng::seed_from_u64(123);
+    (0..BYTECODE_COUNT)
+        .map(|_| {
+            let word_count = 2 * rng.gen_range(150..650) + 1;
+            (0..word_count * 4)
+                .flat_map(|_| rng.gen_range(0_u64..2_048).to_be_bytes())
+                .collect()
+        })
+        .collect()
+}
+
+fn compression_benches(criterion: &mut Criterion) {
+    let bytecodes = generate_bytecodes();
+    let total_len: usize = bytecodes.iter().map(Vec::len).sum();
+    let sequential_pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(1)
+        .build()
+        .unwrap();
+
+    let mut group = criterion.benchmark_group("compress_bytecodes");
+    group.throughput(Throughput::Bytes(total_len as u64));
+    group.bench_function("sequential", |bencher| {
+        bencher.iter_batched(
+            || bytecodes.clone(),
+            |bytecodes| sequential_pool.install(|| compress_bytecodes(bytecodes)),
+            BatchSize::LargeInput,
+        );
+    });
+    group.bench_function("parallel", |bencher| {
+        bencher.iter_batched(
+            || bytecodes.clone(),
+            compress_bytecodes,
+            BatchSize::LargeInput,
+        );
+    });
+    group.finish();
+}
+
+criterion_group!(benches, compression_benches);
+criterion_main!(benches);
@@ -1,12 +1,25 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    io::{self, BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+};
 
+use anyhow::Context as _;
+use rayon::prelude::*;
 use zksync_types::{
     ethabi::{self, Token},
     Address, H256, U256,
 };
-use zksync_utils::bytecode::{hash_bytecode, validate_bytecode, InvalidBytecodeError};
+use zksync_utils::bytecode::{
+    hash_bytecode, validate_bytecode, validate_bytecode_len, InvalidBytecodeError,
+};
 
-use crate::interface::CompressedBytecodeInfo;
+use crate::{
+    interface::{
+        BytecodeCompressionStats, BytecodeCompressionVersion, CompressedBytecodeInfo, Halt,
+        UnknownBytecodeCompressionVersion, VmRevertReason,
+    },
+    utils::metrics::BYTECODE_COMPRESSION_METRICS,
+};
 
 pub(crate) fn be_chunks_to_h256_words(chunks: Vec<[u8; 32]>) -> Vec<H256> {
     chunks.into_iter().map(|el| H256::from_slice(&el)).collect()
@@ -54,6 +67,34 @@ pub(crate) enum FailedToCompressBytecodeError {
     InvalidBytecode(#[from] InvalidBytecodeError),
 }
 
+/// Error returned when one of the bytecodes passed for compression is malformed.
+#[derive(Debug, thiserror::Error)]
+#[error("Bytecode #{index} is malformed: {source}")]
+pub(crate) struct MalformedBytecodeError {
+    /// Index of the malformed bytecode in the input.
+    pub index: usize,
+    #[source]
+    pub source: InvalidBytecodeError,
+}
+
+impl MalformedBytecodeError {
+    /// Returns the halt reason reported for a transaction with the malformed bytecode in its factory deps.
+    pub(crate) fn into_halt(self) -> Halt {
+        Halt::FailedToMarkFactoryDependencies(VmRevertReason::General {
+            msg: self.to_string(),
+            data: vec![],
+        })
+    }
+}
+
+/// Checks that all bytecodes satisfy the preconditions for hashing and compression.
+pub(crate) fn validate_bytecodes(bytecodes: &[Vec<u8>]) -> Result<(), MalformedBytecodeError> {
+    for (index, bytecode) in bytecodes.iter().enumerate() {
+        validate_bytecode(bytecode).map_err(|source| MalformedBytecodeError { index, source })?;
+    }
+    Ok(())
+}
+
 /// Implements, a simple compression algorithm for the bytecode.
 fn compress_to_bytes(code: &[u8]) -> Result<Vec<u8>, FailedToCompressBytecodeError> {
     validate_bytecode(code)?;
@@ -62,7 +103,6 @@ fn compress_to_bytes(code: &[u8]) -> Result<Vec<u8>, FailedToCompressBytecodeErr
     // this is needed to ensure that the determinism during sorting of the statistic, i.e.
     // each element will have unique first occurrence position
     let mut statistic: HashMap<u64, (usize, usize)> = HashMap::new();
-    let mut dictionary: HashMap<u64, u16> = HashMap::new();
     let mut encoded_data: Vec<u8> = Vec::new();
 
     // Split original bytecode into 8-byte chunks.
@@ -75,22 +115,8 @@ fn compress_to_bytes(code: &[u8]) -> Result<Vec<u8>, FailedToCompressBytecodeErr
         statistic.entry(chunk).or_insert((0, position)).0 += 1;
     }
 
-    let mut statistic_sorted_by_value: Vec<_> = statistic.into_iter().collect::<Vec<_>>();
-    statistic_sorted_by_value.sort_by_key(|x| x.1);
-
-    // The dictionary size is limited by 2^16 - 1,
-    if statistic_sorted_by_value.len() > u16::MAX.into() {
-        return Err(FailedToCompressBytecodeError::DictionaryOverflow);
-    }
-
-    // Fill the dictionary with the most popular chunks.
-    // The most popular chunks will be encoded with the smallest indexes, so that
-    // the 255 most popular chunks will be encoded with one zero byte.
-    // And the encoded data will be filled with more zeros, so
-    // the calldata that will be sent to L1 will be cheaper.
-    for (chunk, _) in statistic_sorted_by_value.iter().rev() {
-        dictionary.insert(*chunk, dictionary.len() as u16);
-    }
+    let dictionary = build_dictionary(statistic)?;
+    let indexes = dictionary_indexes(&dictionary);
 
     for chunk_bytes in code.chunks(8) {
         // It is safe to unwrap here, because each chunk is exactly 8 bytes, since
@@ -98,7 +124,7 @@ fn compress_to_bytes(code: &[u8]) -> Result<Vec<u8>, FailedToCompressBytecodeErr
         let chunk = u64::from_be_bytes(chunk_bytes.try_into().unwrap());
 
         // Add the index of the chunk to the encoded data.
-        encoded_data.extend(dictionary.get(&chunk).unwrap().to_be_bytes());
+        encoded_data.extend(indexes[&chunk].to_be_bytes());
     }
 
     // Prepare the raw compressed bytecode in the following format:
@@ -109,24 +135,284 @@ fn compress_to_bytes(code: &[u8]) -> Result<Vec<u8>, FailedToCompressBytecodeErr
     let mut compressed: Vec<u8> = Vec::new();
     compressed.extend((dictionary.len() as u16).to_be_bytes());
 
-    let mut entries: Vec<_> = dictionary.into_iter().map(|(k, v)| (v, k)).collect();
-    entries.sort_unstable();
-    for (_, chunk) in entries {
+    for chunk in dictionary {
         compressed.extend(chunk.to_be_bytes());
     }
     compressed.extend(encoded_data);
     Ok(compressed)
 }
 
+/// Builds the dictionary from the chunk statistic, returning the chunks ordered by their index.
+fn build_dictionary(
+    statistic: HashMap<u64, (usize, usize)>,
+) -> Result<Vec<u64>, FailedToCompressBytecodeError> {
+    let mut statistic_sorted_by_value: Vec<_> = statistic.into_iter().collect::<Vec<_>>();
+    statistic_sorted_by_value.sort_by_key(|x| x.1);
+
+    // The dictionary size is limited by 2^16 - 1,
+    if statistic_sorted_by_value.len() > u16::MAX.into() {
+        return Err(FailedToCompressBytecodeError::DictionaryOverflow);
+    }
+
+    // Fill the dictionary with the most popular chunks.
+    // The most popular chunks will be encoded with the smallest indexes, so that
+    // the 255 most popular chunks will be encoded with one zero byte.
+    // And the encoded data will be filled with more zeros, so
+    // the calldata that will be sent to L1 will be cheaper.
+    Ok(statistic_sorted_by_value
+        .into_iter()
+        .rev()
+        .map(|(chunk, _)| chunk)
+        .collect())
+}
+
+fn dictionary_indexes(dictionary: &[u64]) -> HashMap<u64, u16> {
+    dictionary
+        .iter()
+        .enumerate()
+        .map(|(index, chunk)| (*chunk, index as u16))
+        .collect()
+}
+
+/// Same as [`compress_bytecode()`], but streams the bytecode from `reader` to `writer` instead of holding it
+/// and the compressed data in memory. Peak memory is bounded by the dictionary (at most 2^16 - 1 chunks)
+/// regardless of the input size, and the output is identical to the one of [`compress_bytecode()`].
+///
+/// The dictionary depends on the entire bytecode, so the reader is read twice: first to collect chunk statistics,
+/// then, after seeking back to its initial position, to encode the chunks. On error, `writer` may have received
+/// partial output.
+pub fn compress_bytecode_streaming<R: Read + Seek, W: Write>(
+    mut reader: R,
+    writer: W,
+) -> anyhow::Result<()> {
+    let start = reader
+        .stream_position()
+        .context("failed getting bytecode start position")?;
+    let mut reader = BufReader::new(reader);
+
+    let mut statistic: HashMap<u64, (usize, usize)> = HashMap::new();
+    let mut dictionary_overflow = false;
+    let mut bytecode_len = 0_usize;
+    let mut chunk = [0_u8; 8];
+    loop {
+        let chunk_len = read_chunk(&mut reader, &mut chunk).context("failed reading bytecode")?;
+        bytecode_len += chunk_len;
+        if chunk_len < chunk.len() {
+            break;
+        }
+        // Stop collecting the statistic once the dictionary overflows to bound memory, but read the rest
+        // of the input anyway, so that the bytecode length is validated first, like in `compress_to_bytes()`.
+        if !dictionary_overflow {
+            let position = bytecode_len / 8 - 1;
+            let chunk = u64::from_be_bytes(chunk);
+            statistic.entry(chunk).or_insert((0, position)).0 += 1;
+            dictionary_overflow = statistic.len() > u16::MAX.into();
+        }
+    }
+
+    validate_bytecode_len(bytecode_len).map_err(FailedToCompressBytecodeError::from)?;
+    if dictionary_overflow {
+        return Err(FailedToCompressBytecodeError::DictionaryOverflow.into());
+    }
+    let dictionary = build_dictionary(statistic)?;
+    let indexes = dictionary_indexes(&dictionary);
+
+    let mut writer = BufWriter::new(writer);
+    writer.write_all(&(dictionary.len() as u16).to_be_bytes())?;
+    for chunk in &dictionary {
+        writer.write_all(&chunk.to_be_bytes())?;
+    }
+    drop(dictionary);
+
+    reader
+        .seek(SeekFrom::Start(start))
+        .context("failed seeking to bytecode start")?;
+    for _ in 0..bytecode_len / 8 {
+        reader
+            .read_exact(&mut chunk)
+            .context("failed re-reading bytecode")?;
+        let index = indexes
+            .get(&u64::from_be_bytes(chunk))
+            .context("bytecode has changed during compression")?;
+        writer.write_all(&index.to_be_bytes())?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads up to 8 bytes into `chunk`. Fewer bytes are only returned at the end of the input.
+fn read_chunk(reader: &mut impl Read, chunk: &mut [u8; 8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < chunk.len() {
+        match reader.read(&mut chunk[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+            Err(err) => return Err(err),
+        }
+    }
+    Ok(filled)
+}
+
+/// Compresses the bytecode, moving it into the returned info.
 pub(crate) fn compress(
     bytecode: Vec<u8>,
 ) -> Result<CompressedBytecodeInfo, FailedToCompressBytecodeError> {
     Ok(CompressedBytecodeInfo {
         compressed: compress_to_bytes(&bytecode)?,
         original: bytecode,
+        version: BytecodeCompressionVersion::V1,
     })
 }
 
+/// Error returned when a compressed bytecode cannot be decompressed.
+#[derive(Debug, thiserror::Error)]
+pub enum DecompressBytecodeError {
+    #[error("Compressed bytecode has no version tag")]
+    MissingVersionTag,
+    #[error(transparent)]
+    UnknownVersion(#[from] UnknownBytecodeCompressionVersion),
+    #[error("Compressed bytecode is malformed: {0}")]
+    Malformed(&'static str),
+}
+
+/// Decompresses a bytecode produced by [`CompressedBytecodeInfo::tagged_compressed()`], using the algorithm
+/// the version tag refers to.
+pub fn decompress_tagged(tagged: &[u8]) -> Result<Vec<u8>, DecompressBytecodeError> {
+    let (&tag, compressed) = tagged
+        .split_first()
+        .ok_or(DecompressBytecodeError::MissingVersionTag)?;
+    decompress(BytecodeCompressionVersion::from_tag(tag)?, compressed)
+}
+
+/// Decompresses a bytecode compressed with the given version of the algorithm.
+pub fn decompress(
+    version: BytecodeCompressionVersion,
+    compressed: &[u8],
+) -> Result<Vec<u8>, DecompressBytecodeError> {
+    match version {
+        BytecodeCompressionVersion::V1 => decompress_v1(compressed),
+    }
+}
+
+/// Reverses [`compress_to_bytes()`].
+fn decompress_v1(compressed: &[u8]) -> Result<Vec<u8>, DecompressBytecodeError> {
+    use DecompressBytecodeError::Malformed;
+
+    let (dictionary_len, rest) = compressed
+        .split_first_chunk::<2>()
+        .ok_or(Malformed("missing dictionary length"))?;
+    let dictionary_bytes_len = usize::from(u16::from_be_bytes(*dictionary_len)) * 8;
+    if rest.len() < dictionary_bytes_len {
+        return Err(Malformed("dictionary is truncated"));
+    }
+    let (dictionary, encoded_data) = rest.split_at(dictionary_bytes_len);
+    if encoded_data.len() % 2 != 0 {
+        return Err(Malformed("encoded data has odd length"));
+    }
+
+    let dictionary: Vec<&[u8]> = dictionary.chunks(8).collect();
+    let mut decompressed = Vec::with_capacity(encoded_data.len() * 4);
+    for index in encoded_data.chunks(2) {
+        let index = usize::from(u16::from_be_bytes([index[0], index[1]]));
+        let chunk = dictionary
+            .get(index)
+            .ok_or(Malformed("chunk index is out of the dictionary bounds"))?;
+        decompressed.extend_from_slice(chunk);
+    }
+    Ok(decompressed)
+}
+
+/// Bytecodes of at least this length are compressed by [`compress_bytecode()`] in the streaming mode.
+const STREAMING_COMPRESSION_THRESHOLD: usize = 256 * 1024;
+
+/// Compresses a single bytecode with the default algorithm. This is the compressor used by [`compress_bytecodes()`].
+///
+/// Large bytecodes are compressed with [`compress_bytecode_streaming()`], so that the encoded data isn't
+/// buffered separately from the output. The output is the same in both cases.
+pub fn compress_bytecode(bytecode: &[u8]) -> anyhow::Result<Vec<u8>> {
+    if bytecode.len() < STREAMING_COMPRESSION_THRESHOLD {
+        return Ok(compress_to_bytes(bytecode)?);
+    }
+    let mut compressed = vec![];
+    compress_bytecode_streaming(io::Cursor::new(bytecode), &mut compressed)?;
+    Ok(compressed)
+}
+
+/// Compresses bytecodes in parallel, returning them in the original order. Bytecodes are moved
+/// into the returned infos without copying.
+///
+/// Bytecodes that cannot be compressed are logged at the warn level and are not included in the output.
+pub fn compress_bytecodes(bytecodes: Vec<Vec<u8>>) -> Vec<CompressedBytecodeInfo> {
+    compress_bytecodes_with(bytecodes, compress_bytecode)
+}
+
+/// Same as [`compress_bytecodes()`], but uses the provided compressor instead of the default one,
+/// e.g. to stub compression in tests or to compare alternative algorithms.
+///
+/// The output is labeled with [`BytecodeCompressionVersion::V1`], so only compressors producing data
+/// in this format may be used for bytecodes that are actually published.
+pub fn compress_bytecodes_with<F>(
+    bytecodes: Vec<Vec<u8>>,
+    compressor: F,
+) -> Vec<CompressedBytecodeInfo>
+where
+    F: Fn(&[u8]) -> anyhow::Result<Vec<u8>> + Sync,
+{
+    let compressed: Vec<_> = bytecodes
+        .into_par_iter()
+        .enumerate()
+        .filter_map(|(idx, bytecode)| match compressor(&bytecode) {
+            Ok(compressed) => Some(CompressedBytecodeInfo {
+                compressed,
+                original: bytecode,
+                version: BytecodeCompressionVersion::V1,
+            }),
+            Err(err) => {
+                // Bytecodes that fail to compress are usually invalid, so they cannot be hashed.
+                let len = bytecode.len();
+                tracing::warn!("Failed to compress bytecode #{idx} ({len} bytes): {err:#}");
+                None
+            }
+        })
+        .collect();
+    report_compression_stats(&compressed);
+    compressed
+}
+
+/// Returns compression stats for each of the bytecodes, keyed by the bytecode hash.
+pub fn compression_report(
+    bytecodes: &[CompressedBytecodeInfo],
+) -> Vec<(H256, BytecodeCompressionStats)> {
+    bytecodes
+        .iter()
+        .map(|info| (hash_bytecode(&info.original), info.compression_stats()))
+        .collect()
+}
+
+fn report_compression_stats(bytecodes: &[CompressedBytecodeInfo]) {
+    if bytecodes.is_empty() {
+        return;
+    }
+
+    let metrics = &BYTECODE_COMPRESSION_METRICS;
+    let mut total = BytecodeCompressionStats::default();
+    for info in bytecodes {
+        let stats = info.compression_stats();
+        metrics.ratio.observe(stats.ratio());
+        total = total + stats;
+    }
+    metrics.original_bytes.inc_by(total.original_len as u64);
+    metrics.compressed_bytes.inc_by(total.compressed_len as u64);
+    tracing::debug!(
+        "Compressed {} bytecodes from {} to {} bytes (ratio: {:.3})",
+        bytecodes.len(),
+        total.original_len,
+        total.compressed_len,
+        total.ratio()
+    );
+}
+
 pub(crate) fn encode_call(bytecode: &CompressedBytecodeInfo) -> Vec<u8> {
     let mut bytecode_hash = hash_bytecode(&bytecode.original).as_bytes().to_vec();
     let empty_cell = [0_u8; 32];
@@ -142,40 +428,178 @@ pub(crate) fn encode_call(bytecode: &CompressedBytecodeInfo) -> Vec<u8> {
 
 #[cfg(test)]
 mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
     use super::*;
 
-    fn decompress_bytecode(raw_compressed_bytecode: &[u8]) -> Vec<u8> {
-        let mut decompressed: Vec<u8> = Vec::new();
-        let mut dictionary: Vec<u64> = Vec::new();
+    #[test]
+    fn bytecode_compression() {
+        let example_code = hex::decode("000200000000000200010000000103550000006001100270000000150010019d0000000101200190000000080000c13d0000000001000019004e00160000040f0000000101000039004e00160000040f0000001504000041000000150510009c000000000104801900000040011002100000000001310019000000150320009c0000000002048019000000600220021000000000012100190000004f0001042e000000000100001900000050000104300000008002000039000000400020043f0000000002000416000000000110004c000000240000613d000000000120004c0000004d0000c13d000000200100003900000100001004430000012000000443000001000100003900000040020000390000001d03000041004e000a0000040f000000000120004c0000004d0000c13d0000000001000031000000030110008c0000004d0000a13d0000000101000367000000000101043b0000001601100197000000170110009c0000004d0000c13d0000000101000039000000000101041a0000000202000039000000000202041a000000400300043d00000040043000390000001805200197000000000600041a0000000000540435000000180110019700000020043000390000000000140435000000a0012002700000001901100197000000600430003900000000001404350000001a012001980000001b010000410000000001006019000000b8022002700000001c02200197000000000121019f0000008002300039000000000012043500000018016001970000000000130435000000400100043d0000000002130049000000a0022000390000000003000019004e000a0000040f004e00140000040f0000004e000004320000004f0001042e000000500001043000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ffffffffffffffff000000000000000000000000000000000000000000000000000000008903573000000000000000000000000000000000000000000000000000000000000000000000000000000000ffffffffffffffffffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000ffffff0000000000008000000000000000000000000000000000000000000000000000ffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80000000000000000000000000000000000000000000000000000000000000007fffff00000002000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").unwrap();
+        let compressed = compress_to_bytes(&example_code).unwrap();
+        let decompressed = decompress_v1(&compressed).unwrap();
+
+        assert_eq!(example_code, decompressed);
+    }
+
+    #[test]
+    fn tagged_bytecode_roundtrip() {
+        let example_code =
+            hex::decode("0000000000000000111111111111111111111111111111112222222222222222")
+                .unwrap();
+        let info = compress(example_code.clone()).unwrap();
+        assert_eq!(info.version, BytecodeCompressionVersion::V1);
 
-        let dictionary_len = u16::from_be_bytes(raw_compressed_bytecode[0..2].try_into().unwrap());
-        for index in 0..dictionary_len {
-            let chunk = u64::from_be_bytes(
-                raw_compressed_bytecode[2 + index as usize * 8..10 + index as usize * 8]
-                    .try_into()
-                    .unwrap(),
+        let tagged = info.tagged_compressed();
+        assert_eq!(tagged[0], BytecodeCompressionVersion::V1.tag());
+        assert_eq!(tagged[1..], info.compressed);
+        assert_eq!(decompress_tagged(&tagged).unwrap(), example_code);
+    }
+
+    #[test]
+    fn unknown_compression_versions_are_rejected() {
+        let compressed = compress(vec![0; 32]).unwrap().compressed;
+        for tag in [0, 2, u8::MAX] {
+            let mut tagged = vec![tag];
+            tagged.extend_from_slice(&compressed);
+            let err = decompress_tagged(&tagged).unwrap_err();
+            assert!(
+                matches!(
+                    err,
+                    DecompressBytecodeError::UnknownVersion(UnknownBytecodeCompressionVersion(t))
+                        if t == tag
+                ),
+                "{err}"
             );
-            dictionary.push(chunk);
         }
+        assert!(matches!(
+            decompress_tagged(&[]).unwrap_err(),
+            DecompressBytecodeError::MissingVersionTag
+        ));
+    }
 
-        let encoded_data = &raw_compressed_bytecode[2 + dictionary_len as usize * 8..];
-        for index_bytes in encoded_data.chunks(2) {
-            let index = u16::from_be_bytes(index_bytes.try_into().unwrap());
+    #[test]
+    fn malformed_compressed_bytecodes_are_rejected() {
+        let mut compressed = compress(vec![0; 32]).unwrap().compressed;
+        // Point the first chunk to a non-existing dictionary entry.
+        let index_offset = compressed.len() - 8;
+        compressed[index_offset..index_offset + 2].copy_from_slice(&[0, 5]);
+        let err = decompress(BytecodeCompressionVersion::V1, &compressed).unwrap_err();
+        assert!(
+            matches!(err, DecompressBytecodeError::Malformed(_)),
+            "{err}"
+        );
+
+        let err = decompress(BytecodeCompressionVersion::V1, &[0, 3, 0]).unwrap_err();
+        assert!(
+            matches!(err, DecompressBytecodeError::Malformed(_)),
+            "{err}"
+        );
+    }
 
-            let chunk = dictionary[index as usize];
-            decompressed.extend(chunk.to_be_bytes());
-        }
+    #[test]
+    fn malformed_bytecodes_are_reported_with_index() {
+        let valid = vec![0_u8; 32];
+        let not_divisible_by_32 = vec![0_u8; 33];
+        let even_words = vec![0_u8; 64];
+
+        validate_bytecodes(&[valid.clone()]).unwrap();
+
+        let err = validate_bytecodes(&[valid.clone(), not_divisible_by_32]).unwrap_err();
+        assert_eq!(err.index, 1);
+        assert!(matches!(
+            err.source,
+            InvalidBytecodeError::BytecodeLengthIsNotDivisibleBy32
+        ));
+
+        let err = validate_bytecodes(&[even_words, valid]).unwrap_err();
+        assert_eq!(err.index, 0);
+        assert!(matches!(
+            err.source,
+            InvalidBytecodeError::BytecodeLengthInWordsIsEven
+        ));
+    }
 
-        decompressed
+    #[test]
+    fn parallel_compression_matches_sequential() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut bytecodes: Vec<_> = (0..49_u64)
+            .map(|i| {
+                let word_count = (2 * i + 1) * 31;
+                (0..word_count * 4)
+                    .flat_map(|_| rng.gen_range(0..(i + 1) * 64).to_be_bytes())
+                    .collect::<Vec<u8>>()
+            })
+            .collect();
+        // Bytecodes with an even number of words cannot be compressed.
+        bytecodes.insert(10, vec![0; 64]);
+
+        let sequential: Vec<_> = bytecodes
+            .iter()
+            .filter_map(|bytecode| compress(bytecode.clone()).ok())
+            .collect();
+        assert_eq!(sequential.len(), 49);
+        assert_eq!(compress_bytecodes(bytecodes), sequential);
     }
 
     #[test]
-    fn bytecode_compression() {
-        let example_code = hex::decode("000200000000000200010000000103550000006001100270000000150010019d0000000101200190000000080000c13d0000000001000019004e00160000040f0000000101000039004e00160000040f0000001504000041000000150510009c000000000104801900000040011002100000000001310019000000150320009c0000000002048019000000600220021000000000012100190000004f0001042e000000000100001900000050000104300000008002000039000000400020043f0000000002000416000000000110004c000000240000613d000000000120004c0000004d0000c13d000000200100003900000100001004430000012000000443000001000100003900000040020000390000001d03000041004e000a0000040f000000000120004c0000004d0000c13d0000000001000031000000030110008c0000004d0000a13d0000000101000367000000000101043b0000001601100197000000170110009c0000004d0000c13d0000000101000039000000000101041a0000000202000039000000000202041a000000400300043d00000040043000390000001805200197000000000600041a0000000000540435000000180110019700000020043000390000000000140435000000a0012002700000001901100197000000600430003900000000001404350000001a012001980000001b010000410000000001006019000000b8022002700000001c02200197000000000121019f0000008002300039000000000012043500000018016001970000000000130435000000400100043d0000000002130049000000a0022000390000000003000019004e000a0000040f004e00140000040f0000004e000004320000004f0001042e000000500001043000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000ffffffffffffffff000000000000000000000000000000000000000000000000000000008903573000000000000000000000000000000000000000000000000000000000000000000000000000000000ffffffffffffffffffffffffffffffffffffffff0000000000000000000000000000000000000000000000000000000000ffffff0000000000008000000000000000000000000000000000000000000000000000ffffffffffffffffffffffffffffffffffffffffffffffffffffffffff80000000000000000000000000000000000000000000000000000000000000007fffff00000002000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000").unwrap();
-        let compressed = compress_to_bytes(&example_code).unwrap();
-        let decompressed = decompress_bytecode(&compressed);
+    fn injected_compressor_is_used() {
+        let bytecodes = vec![vec![1_u8; 32], vec![2_u8; 96]];
+
+        let noop = compress_bytecodes_with(bytecodes.clone(), |bytecode| Ok(bytecode.to_vec()));
+        assert_eq!(noop.len(), 2);
+        for (info, bytecode) in noop.iter().zip(&bytecodes) {
+            assert_eq!(info.original, *bytecode);
+            assert_eq!(info.compressed, *bytecode);
+        }
 
-        assert_eq!(example_code, decompressed);
+        let failing = compress_bytecodes_with(bytecodes.clone(), |bytecode| {
+            anyhow::ensure!(bytecode.len() < 64, "too long");
+            Ok(vec![])
+        });
+        assert_eq!(failing.len(), 1);
+        assert_eq!(failing[0].original, bytecodes[0]);
+    }
+
+    #[test]
+    fn streaming_compression_matches_one_shot() {
+        let mut rng = StdRng::seed_from_u64(345);
+        // The largest valid bytecode, with a dictionary close to the size limit.
+        let word_count = u64::from(u16::MAX);
+        let bytecode: Vec<u8> = (0..word_count * 4)
+            .flat_map(|_| rng.gen_range(0..60_000_u64).to_be_bytes())
+            .collect();
+
+        let mut streamed = vec![];
+        compress_bytecode_streaming(io::Cursor::new(&bytecode), &mut streamed).unwrap();
+        assert_eq!(streamed, compress_to_bytes(&bytecode).unwrap());
+        // Bytecodes of this size are streamed by the default compressor as well.
+        assert!(bytecode.len() >= STREAMING_COMPRESSION_THRESHOLD);
+        let [info] = compress_bytecodes(vec![bytecode.clone()])
+            .try_into()
+            .unwrap();
+        assert_eq!(info.compressed, streamed);
+
+        // The reader is rewound to its initial position rather than to the start of the input.
+        let mut prefixed = vec![0xff; 5];
+        prefixed.extend_from_slice(&bytecode);
+        let mut reader = io::Cursor::new(&prefixed);
+        reader.set_position(5);
+        let mut streamed_from_offset = vec![];
+        compress_bytecode_streaming(reader, &mut streamed_from_offset).unwrap();
+        assert_eq!(streamed_from_offset, streamed);
+
+        for invalid in [
+            vec![0_u8; 64],
+            vec![0_u8; 33],
+            bytecode[..bytecode.len() - 4].to_vec(),
+        ] {
+            let err =
+                compress_bytecode_streaming(io::Cursor::new(&invalid), io::sink()).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                compress_to_bytes(&invalid).unwrap_err().to_string()
+            );
+        }
     }
 
     #[test]
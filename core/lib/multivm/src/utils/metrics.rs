@@ -0,0 +1,21 @@
+use vise::{Buckets, Counter, Histogram, Metrics};
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "vm_bytecode_compression")]
+pub(crate) struct BytecodeCompressionMetrics {
+    /// Total size of successfully compressed bytecodes before compression.
+    pub original_bytes: Counter,
+    /// Total size of successfully compressed bytecodes after compression.
+    pub compressed_bytes: Counter,
+    /// Ratio of the compressed to the original size for each compressed bytecode.
+    #[metrics(buckets = Buckets::linear(0.1..=1.0, 0.1))]
+    pub ratio: Histogram<f64>,
+    /// Number of bytecodes taken from the compressed bytecode cache of the fast VM.
+    pub cache_hits: Counter,
+    /// Number of bytecodes missing from the compressed bytecode cache of the fast VM.
+    pub cache_misses: Counter,
+}
+
+#[vise::register]
+pub(crate) static BYTECODE_COMPRESSION_METRICS: vise::Global<BytecodeCompressionMetrics> =
+    vise::Global::new();
@@ -4,12 +4,20 @@ use zksync_types::{
     U256,
 };
 
-pub use self::deduplicator::{ModifiedSlot, StorageWritesDeduplicator};
+pub use self::{
+    bytecode::{
+        compress_bytecode, compress_bytecode_streaming, compress_bytecodes,
+        compress_bytecodes_with, compression_report, decompress, decompress_tagged,
+        DecompressBytecodeError,
+    },
+    deduplicator::{ModifiedSlot, StorageWritesDeduplicator},
+};
 use crate::interface::L1BatchEnv;
 
 pub(crate) mod bytecode;
 mod deduplicator;
 pub(crate) mod events;
+pub(crate) mod metrics;
 
 /// Calculates the base fee and gas per pubdata for the given L1 gas price.
 pub fn derive_base_fee_and_gas_per_pubdata(
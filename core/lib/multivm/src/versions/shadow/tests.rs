@@ -126,6 +126,13 @@ impl TestedVm for ShadowedFastVm {
             ShadowMut::Shadow(vm) => vm.push_transaction_with_refund(tx.clone(), refund),
         });
     }
+
+    fn set_bytecode_validation(&mut self, enabled: bool) {
+        self.get_mut("set_bytecode_validation", |r| match r {
+            ShadowMut::Main(vm) => vm.set_bytecode_validation(enabled),
+            ShadowMut::Shadow(vm) => vm.set_bytecode_validation(enabled),
+        });
+    }
 }
 
 mod block_tip {
@@ -158,6 +165,16 @@ mod bytecode_publishing {
     fn bytecode_publishing() {
         test_bytecode_publishing::<super::ShadowedFastVm>();
     }
+
+    #[test]
+    fn malformed_factory_deps_halt_transaction() {
+        test_malformed_factory_deps_halt_transaction::<super::ShadowedFastVm>();
+    }
+
+    #[test]
+    fn malformed_factory_deps_are_dropped_in_bootloader_mode() {
+        test_malformed_factory_deps_are_dropped_in_bootloader_mode::<super::ShadowedFastVm>();
+    }
 }
 
 mod circuits {
@@ -2,7 +2,9 @@ use zksync_test_account::TxType;
 
 use super::{default_pubdata_builder, read_test_contract, tester::VmTesterBuilder, TestedVm};
 use crate::{
-    interface::{InspectExecutionMode, TxExecutionMode, VmEvent, VmInterfaceExt},
+    interface::{
+        ExecutionResult, Halt, InspectExecutionMode, TxExecutionMode, VmEvent, VmInterfaceExt,
+    },
     utils::bytecode,
 };
 
@@ -42,3 +44,97 @@ pub(crate) fn test_bytecode_publishing<VM: TestedVm>() {
         "Bytecode not published"
     );
 }
+
+pub(crate) fn test_bytecode_publishing_is_deduplicated_within_batch<VM: TestedVm>() {
+    // Two transactions pushed before either is executed share a bytecode; it should be published once.
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_rich_accounts(1)
+        .build::<VM>();
+
+    let counter = read_test_contract();
+    let account = &mut vm.rich_accounts[0];
+    let compressed_bytecode = bytecode::compress(counter.clone()).unwrap().compressed;
+
+    let first_tx = account.get_deploy_tx(&counter, None, TxType::L2).tx;
+    let second_tx = account.get_deploy_tx(&counter, None, TxType::L2).tx;
+    let push_result = vm.vm.push_transaction(first_tx);
+    assert_eq!(push_result.compressed_bytecodes.len(), 1);
+    let push_result = vm.vm.push_transaction(second_tx);
+    assert!(push_result.compressed_bytecodes.is_empty());
+
+    for _ in 0..2 {
+        let result = vm.vm.execute(InspectExecutionMode::OneTx);
+        assert!(!result.result.is_failed(), "Transaction wasn't successful");
+    }
+
+    vm.vm.finish_batch(default_pubdata_builder());
+
+    let state = vm.vm.get_current_execution_state();
+    let long_messages = VmEvent::extract_long_l2_to_l1_messages(&state.events);
+    let publish_count = long_messages
+        .iter()
+        .filter(|message| **message == compressed_bytecode)
+        .count();
+    assert_eq!(publish_count, 1);
+}
+
+pub(crate) fn test_malformed_factory_deps_halt_transaction<VM: TestedVm>() {
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_rich_accounts(1)
+        .build::<VM>();
+    vm.vm.set_bytecode_validation(true);
+
+    let counter = read_test_contract();
+    let account = &mut vm.rich_accounts[0];
+    let tx = account.get_deploy_tx(&counter, None, TxType::L2).tx;
+    let mut malformed_tx = tx.clone();
+    malformed_tx.execute.factory_deps.push(vec![0; 33]);
+
+    let push_result = vm.vm.push_transaction(malformed_tx);
+    assert!(push_result.compressed_bytecodes.is_empty());
+    let result = vm.vm.execute(InspectExecutionMode::OneTx);
+    let ExecutionResult::Halt {
+        reason: Halt::FailedToMarkFactoryDependencies(reason),
+    } = &result.result
+    else {
+        panic!("Unexpected result: {:?}", result.result);
+    };
+    assert!(
+        reason.to_string().starts_with("Bytecode #1 is malformed"),
+        "{reason}"
+    );
+
+    // The rejected transaction isn't included, so the same transaction with valid factory deps succeeds.
+    vm.vm.push_transaction(tx);
+    let result = vm.vm.execute(InspectExecutionMode::OneTx);
+    assert!(!result.result.is_failed(), "Transaction wasn't successful");
+}
+
+pub(crate) fn test_malformed_factory_deps_are_dropped_in_bootloader_mode<VM: TestedVm>() {
+    let mut vm = VmTesterBuilder::new()
+        .with_empty_in_memory_storage()
+        .with_execution_mode(TxExecutionMode::VerifyExecute)
+        .with_rich_accounts(1)
+        .build::<VM>();
+    vm.vm.set_bytecode_validation(true);
+
+    let counter = read_test_contract();
+    let compressed_bytecode = bytecode::compress(counter.clone()).unwrap().compressed;
+    let account = &mut vm.rich_accounts[0];
+    let mut malformed_tx = account.get_deploy_tx(&counter, None, TxType::L2).tx;
+    malformed_tx.execute.factory_deps.push(vec![0; 33]);
+
+    vm.vm.push_transaction(malformed_tx);
+    // The rejection isn't reported outside the `OneTx` mode; the transaction is silently dropped.
+    let result = vm.vm.execute(InspectExecutionMode::Bootloader);
+    assert!(!result.result.is_failed(), "{:?}", result.result);
+    let long_messages = VmEvent::extract_long_l2_to_l1_messages(&result.logs.events);
+    assert!(
+        !long_messages.contains(&compressed_bytecode),
+        "Rejected transaction was executed"
+    );
+}
@@ -228,4 +228,7 @@ pub(crate) trait TestedVm:
 
     /// Pushes a transaction with predefined refund value.
     fn push_transaction_with_refund(&mut self, tx: Transaction, refund: u64);
+
+    /// Enables validation of factory deps for pushed transactions.
+    fn set_bytecode_validation(&mut self, enabled: bool);
 }
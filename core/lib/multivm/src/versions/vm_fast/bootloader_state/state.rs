@@ -1,7 +1,8 @@
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::HashMap};
 
 use once_cell::sync::OnceCell;
-use zksync_types::{L2ChainId, U256};
+use zksync_types::{L2ChainId, H256, U256};
+use zksync_utils::bytecode::hash_bytecode;
 
 use super::{
     l2_block::BootloaderL2Block,
@@ -34,6 +35,9 @@ pub struct BootloaderState {
     l2_blocks: Vec<BootloaderL2Block>,
     /// The number of 32-byte words spent on the already included compressed bytecodes.
     compressed_bytecodes_encoding: usize,
+    /// Hashes of the bytecodes compressed by the pushed transactions, mapped to the index of the first
+    /// transaction that publishes them. Later transactions in the batch don't need to publish them again.
+    published_bytecodes: HashMap<H256, usize>,
     /// Initial memory of bootloader
     initial_memory: BootloaderMemory,
     /// Mode of txs for execution, it can be changed once per vm lunch
@@ -54,6 +58,7 @@ impl BootloaderState {
         Self {
             tx_to_execute: 0,
             compressed_bytecodes_encoding: 0,
+            published_bytecodes: HashMap::new(),
             l2_blocks: vec![l2_block],
             initial_memory,
             execution_mode,
@@ -102,6 +107,12 @@ impl BootloaderState {
         chain_id: L2ChainId,
     ) -> BootloaderMemory {
         let tx_offset = self.free_tx_offset();
+        let tx_index = self.free_tx_index();
+        for info in &compressed_bytecodes {
+            self.published_bytecodes
+                .entry(hash_bytecode(&info.original))
+                .or_insert(tx_index);
+        }
         let bootloader_tx = BootloaderTx::new(
             tx,
             predefined_refund,
@@ -189,6 +200,11 @@ impl BootloaderState {
         l2_block.first_tx_index + l2_block.txs.len()
     }
 
+    /// Checks whether a bytecode is already published by one of the transactions pushed in this batch.
+    pub(crate) fn is_bytecode_published(&self, hash: &H256) -> bool {
+        self.published_bytecodes.contains_key(hash)
+    }
+
     pub(crate) fn get_last_tx_compressed_bytecodes(&self) -> &[CompressedBytecodeInfo] {
         if let Some(tx) = self.last_l2_block().txs.last() {
             &tx.compressed_bytecodes
@@ -276,6 +292,9 @@ impl BootloaderState {
         }
         self.last_mut_l2_block()
             .apply_snapshot(snapshot.last_l2_block);
+        let free_tx_index = self.free_tx_index();
+        self.published_bytecodes
+            .retain(|_, tx_index| *tx_index < free_tx_index);
 
         if !snapshot.is_pubdata_information_provided {
             self.pubdata_information = Default::default();
@@ -1,43 +1,176 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
 use itertools::Itertools;
 use zksync_types::{h256_to_u256, H256};
 use zksync_utils::bytecode::hash_bytecode;
 
 use super::Vm;
 use crate::{
-    interface::{storage::ReadStorage, CompressedBytecodeInfo},
-    utils::bytecode,
+    interface::{storage::ReadStorage, BytecodeCompressionVersion, CompressedBytecodeInfo},
+    utils::{bytecode, metrics::BYTECODE_COMPRESSION_METRICS},
 };
 
+/// Maximum number of bytecodes kept in [`CompressedBytecodeCache`].
+const COMPRESSED_BYTECODE_CACHE_CAPACITY: usize = 256;
+
+/// Compressed bytecodes keyed by the bytecode hash, so that bytecodes deployed repeatedly aren't recompressed.
+/// When the cache is full, the oldest entries are evicted first.
+///
+/// The cache is a cheaply cloneable handle; clones share their entries, so a single cache can be passed
+/// to all VMs created by the caller (e.g., to the VMs for successive L1 batches).
+#[derive(Debug, Clone, Default)]
+pub struct CompressedBytecodeCache(Arc<Mutex<CacheEntries>>);
+
+#[derive(Debug, Default)]
+struct CacheEntries {
+    entries: HashMap<H256, Vec<u8>>,
+    insertion_order: VecDeque<H256>,
+}
+
+impl CompressedBytecodeCache {
+    fn entries(&self) -> MutexGuard<'_, CacheEntries> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get(&self, hash: &H256) -> Option<Vec<u8>> {
+        let compressed = self.entries().entries.get(hash).cloned();
+        if compressed.is_some() {
+            BYTECODE_COMPRESSION_METRICS.cache_hits.inc();
+        } else {
+            BYTECODE_COMPRESSION_METRICS.cache_misses.inc();
+        }
+        compressed
+    }
+
+    fn insert(&self, hash: H256, compressed: Vec<u8>) {
+        let mut cache = self.entries();
+        if cache.entries.insert(hash, compressed).is_some() {
+            return;
+        }
+        cache.insertion_order.push_back(hash);
+        if cache.insertion_order.len() > COMPRESSED_BYTECODE_CACHE_CAPACITY {
+            if let Some(oldest_hash) = cache.insertion_order.pop_front() {
+                cache.entries.remove(&oldest_hash);
+            }
+        }
+    }
+}
+
 impl<S: ReadStorage, Tr> Vm<S, Tr> {
     /// Checks the last transaction has successfully published compressed bytecodes and returns `true` if there is at least one is still unknown.
     pub(crate) fn has_unpublished_bytecodes(&mut self) -> bool {
+        !self.unpublished_bytecode_hashes().is_empty()
+    }
+
+    /// Returns hashes of the compressed bytecodes from the last transaction that are neither known to storage
+    /// nor present in the bytecode cache.
+    pub(crate) fn unpublished_bytecode_hashes(&mut self) -> Vec<H256> {
         self.bootloader_state
             .get_last_tx_compressed_bytecodes()
             .iter()
-            .any(|info| {
-                let hash_bytecode = hash_bytecode(&info.original);
-                let is_bytecode_known = self.world.storage.is_bytecode_known(&hash_bytecode);
-
-                let is_bytecode_known_cache = self
-                    .world
-                    .bytecode_cache
-                    .contains_key(&h256_to_u256(hash_bytecode));
+            .map(|info| hash_bytecode(&info.original))
+            .filter(|hash| {
+                let is_bytecode_known = self.world.storage.is_bytecode_known(hash);
+                let is_bytecode_known_cache =
+                    self.world.bytecode_cache.contains_key(&h256_to_u256(*hash));
                 !(is_bytecode_known || is_bytecode_known_cache)
             })
+            .collect()
     }
 }
 
+/// Compresses the bytecodes that are not yet known, preserving their original order.
+///
+/// Bytecodes found in `cache` are not recompressed; freshly compressed ones are added to it.
+/// Each unknown bytecode is copied exactly once, into the returned info.
 pub(crate) fn compress_bytecodes(
     bytecodes: &[Vec<u8>],
+    cache: &CompressedBytecodeCache,
     mut is_bytecode_known: impl FnMut(H256) -> bool,
 ) -> Vec<CompressedBytecodeInfo> {
-    bytecodes
+    let mut uncached_bytecodes = vec![];
+    let unknown_bytecodes: Vec<_> = bytecodes
         .iter()
         .enumerate()
         .sorted_by_key(|(_idx, dep)| *dep)
         .dedup_by(|x, y| x.1 == y.1)
-        .filter(|(_idx, dep)| !is_bytecode_known(hash_bytecode(dep)))
-        .sorted_by_key(|(idx, _dep)| *idx)
-        .filter_map(|(_idx, dep)| bytecode::compress(dep.clone()).ok())
+        .map(|(idx, dep)| (idx, hash_bytecode(dep), dep))
+        .filter(|(_idx, hash, _dep)| !is_bytecode_known(*hash))
+        .sorted_by_key(|(idx, _hash, _dep)| *idx)
+        .map(|(_idx, hash, dep)| match cache.get(&hash) {
+            Some(compressed) => {
+                let info = CompressedBytecodeInfo {
+                    original: dep.clone(),
+                    compressed,
+                    version: BytecodeCompressionVersion::V1,
+                };
+                (hash, Some(info))
+            }
+            None => {
+                uncached_bytecodes.push(dep.clone());
+                (hash, None)
+            }
+        })
+        .collect();
+
+    let mut newly_compressed: HashMap<_, _> = bytecode::compress_bytecodes(uncached_bytecodes)
+        .into_iter()
+        .map(|info| (hash_bytecode(&info.original), info))
+        .collect();
+
+    unknown_bytecodes
+        .into_iter()
+        .filter_map(|(hash, cached)| match cached {
+            Some(info) => Some(info),
+            None => {
+                let info = newly_compressed.remove(&hash)?;
+                cache.insert(hash, info.compressed.clone());
+                Some(info)
+            }
+        })
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::versions::testonly::read_test_contract;
+
+    #[test]
+    fn compressed_bytecode_cache_is_shared_between_vms() {
+        let counter = read_test_contract();
+        let hash = hash_bytecode(&counter);
+        let bytecodes = [counter.clone()];
+
+        // The VM for the first batch compresses the bytecode and caches the result.
+        let cache = CompressedBytecodeCache::default();
+        let compressed = compress_bytecodes(&bytecodes, &cache, |_| false);
+        assert_eq!(compressed.len(), 1);
+        assert_eq!(compressed[0].original, counter);
+
+        // The VM for the next batch receives a clone of the cache and sees the cached entry.
+        let next_batch_cache = cache.clone();
+        assert_eq!(
+            next_batch_cache.get(&hash),
+            Some(compressed[0].compressed.clone())
+        );
+        let recompressed = compress_bytecodes(&bytecodes, &next_batch_cache, |_| false);
+        assert_eq!(recompressed, compressed);
+    }
+
+    #[test]
+    fn cached_bytecodes_are_not_recompressed() {
+        let counter = read_test_contract();
+        let cache = CompressedBytecodeCache::default();
+        let marker = vec![0xff; 4];
+        cache.insert(hash_bytecode(&counter), marker.clone());
+
+        let compressed = compress_bytecodes(&[counter.clone()], &cache.clone(), |_| false);
+        assert_eq!(compressed.len(), 1);
+        assert_eq!(compressed[0].original, counter);
+        assert_eq!(compressed[0].compressed, marker);
+    }
+}
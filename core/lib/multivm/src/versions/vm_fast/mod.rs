@@ -1,6 +1,6 @@
 pub use zksync_vm2::interface;
 
-pub use self::vm::Vm;
+pub use self::{bytecode::CompressedBytecodeCache, vm::Vm};
 
 mod bootloader_state;
 mod bytecode;
@@ -1,6 +1,28 @@
-use crate::{versions::testonly::bytecode_publishing::test_bytecode_publishing, vm_fast::Vm};
+use crate::{
+    versions::testonly::bytecode_publishing::{
+        test_bytecode_publishing, test_bytecode_publishing_is_deduplicated_within_batch,
+        test_malformed_factory_deps_are_dropped_in_bootloader_mode,
+        test_malformed_factory_deps_halt_transaction,
+    },
+    vm_fast::Vm,
+};
 
 #[test]
 fn bytecode_publishing() {
     test_bytecode_publishing::<Vm<_>>();
 }
+
+#[test]
+fn bytecode_publishing_is_deduplicated_within_batch() {
+    test_bytecode_publishing_is_deduplicated_within_batch::<Vm<_>>();
+}
+
+#[test]
+fn malformed_factory_deps_halt_transaction() {
+    test_malformed_factory_deps_halt_transaction::<Vm<_>>();
+}
+
+#[test]
+fn malformed_factory_deps_are_dropped_in_bootloader_mode() {
+    test_malformed_factory_deps_are_dropped_in_bootloader_mode::<Vm<_>>();
+}
@@ -167,4 +167,8 @@ impl TestedVm for Vm<ImmutableStorageView<InMemoryStorage>> {
     fn push_transaction_with_refund(&mut self, tx: Transaction, refund: u64) {
         self.push_transaction_inner(tx, refund, true);
     }
+
+    fn set_bytecode_validation(&mut self, enabled: bool) {
+        self.set_bytecode_validation(enabled);
+    }
 }
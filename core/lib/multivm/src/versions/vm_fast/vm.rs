@@ -27,7 +27,7 @@ use zksync_vm_interface::{pubdata::PubdataBuilder, InspectExecutionMode};
 
 use super::{
     bootloader_state::{BootloaderState, BootloaderStateSnapshot},
-    bytecode::compress_bytecodes,
+    bytecode::{compress_bytecodes, CompressedBytecodeCache},
     circuits_tracer::CircuitsTracer,
     evm_deploy_tracer::{DynamicBytecodes, EvmDeployTracer},
     hook::Hook,
@@ -45,7 +45,7 @@ use crate::{
         VmInterfaceHistoryEnabled, VmRevertReason, VmTrackingContracts,
     },
     is_supported_by_fast_vm,
-    utils::events::extract_l2tol1logs_from_l1_messenger,
+    utils::{bytecode::validate_bytecodes, events::extract_l2tol1logs_from_l1_messenger},
     vm_fast::{
         bootloader_state::utils::{apply_l2_block, apply_pubdata_to_memory},
         events::merge_events,
@@ -103,6 +103,10 @@ pub struct Vm<S, Tr = ()> {
     pub(super) batch_env: L1BatchEnv,
     pub(super) system_env: SystemEnv,
     snapshot: Option<VmSnapshot>,
+    /// Whether factory deps of pushed transactions are validated, see [`Self::set_bytecode_validation()`].
+    validate_bytecodes: bool,
+    /// Halt reason of the last pushed transaction if it was rejected instead of being pushed.
+    rejected_tx_halt: Option<Halt>,
     #[cfg(test)]
     enforced_state_diffs: Option<Vec<StateDiffRecord>>,
 }
@@ -171,6 +175,8 @@ impl<S: ReadStorage, Tr: Tracer + Default> Vm<S, Tr> {
             system_env,
             batch_env,
             snapshot: None,
+            validate_bytecodes: false,
+            rejected_tx_halt: None,
             #[cfg(test)]
             enforced_state_diffs: None,
         };
@@ -462,12 +468,39 @@ impl<S: ReadStorage, Tr: Tracer + Default> Vm<S, Tr> {
         }
     }
 
+    /// Enables checking that factory deps of pushed transactions are well-formed bytecodes before they are
+    /// hashed and compressed. A transaction with malformed factory deps is not executed; it halts with
+    /// [`Halt::FailedToMarkFactoryDependencies`] naming the malformed bytecode instead.
+    ///
+    /// The halt is only reported if the transaction is executed in the `OneTx` mode right after it's pushed.
+    /// If the VM is run in the batch or bootloader mode instead, the rejected transaction is dropped
+    /// without being reported.
+    ///
+    /// Disabled by default, since factory deps are validated before transactions reach the VM.
+    pub fn set_bytecode_validation(&mut self, enabled: bool) {
+        self.validate_bytecodes = enabled;
+    }
+
+    /// Sets the cache of compressed bytecodes used by this VM. By default, each VM uses its own cache;
+    /// sharing a cache among VMs (e.g., for successive L1 batches) avoids recompressing the same bytecodes.
+    pub fn set_compressed_bytecode_cache(&mut self, cache: CompressedBytecodeCache) {
+        self.world.compressed_bytecode_cache = cache;
+    }
+
     pub(crate) fn push_transaction_inner(
         &mut self,
         tx: zksync_types::Transaction,
         refund: u64,
         with_compression: bool,
     ) {
+        self.rejected_tx_halt = None;
+        if self.validate_bytecodes {
+            if let Err(err) = validate_bytecodes(&tx.execute.factory_deps) {
+                self.rejected_tx_halt = Some(err.into_halt());
+                return;
+            }
+        }
+
         let tx: TransactionData = tx.into();
         let overhead = tx.overhead_gas();
 
@@ -477,13 +510,18 @@ impl<S: ReadStorage, Tr: Tracer + Default> Vm<S, Tr> {
             // L1 transactions do not need compression
             vec![]
         } else {
-            compress_bytecodes(&tx.factory_deps, |hash| {
-                self.inner
-                    .world_diff()
-                    .get_storage_state()
-                    .get(&(KNOWN_CODES_STORAGE_ADDRESS, h256_to_u256(hash)))
-                    .map(|x| !x.is_zero())
-                    .unwrap_or_else(|| self.world.storage.is_bytecode_known(&hash))
+            // Bytecodes published earlier in the batch are treated as known, even if the publishing
+            // transaction isn't executed yet.
+            let cache = &self.world.compressed_bytecode_cache;
+            compress_bytecodes(&tx.factory_deps, cache, |hash| {
+                self.bootloader_state.is_bytecode_published(&hash)
+                    || self
+                        .inner
+                        .world_diff()
+                        .get_storage_state()
+                        .get(&(KNOWN_CODES_STORAGE_ADDRESS, h256_to_u256(hash)))
+                        .map(|x| !x.is_zero())
+                        .unwrap_or_else(|| self.world.storage.is_bytecode_known(&hash))
             })
         };
 
@@ -579,6 +617,13 @@ impl<S: ReadStorage, Tr: Tracer + Default> Vm<S, Tr> {
         tracer: &mut Tr,
         execution_mode: VmExecutionMode,
     ) -> VmExecutionResultAndLogs {
+        // A rejected transaction isn't in the bootloader memory, so there's nothing to execute for it.
+        if let Some(reason) = self.rejected_tx_halt.take() {
+            if matches!(execution_mode, VmExecutionMode::OneTx) {
+                return VmExecutionResultAndLogs::mock(ExecutionResult::Halt { reason });
+            }
+        }
+
         let mut track_refunds = false;
         if matches!(execution_mode, VmExecutionMode::OneTx) {
             // Move the pointer to the next transaction
@@ -699,11 +744,13 @@ impl<S: ReadStorage, Tr: Tracer + Default + 'static> VmInterface for Vm<S, Tr> {
 
     fn push_transaction(&mut self, tx: Transaction) -> PushTransactionResult<'_> {
         self.push_transaction_inner(tx, 0, true);
+        let compressed_bytecodes: &[_] = if self.rejected_tx_halt.is_some() {
+            &[]
+        } else {
+            self.bootloader_state.get_last_tx_compressed_bytecodes()
+        };
         PushTransactionResult {
-            compressed_bytecodes: self
-                .bootloader_state
-                .get_last_tx_compressed_bytecodes()
-                .into(),
+            compressed_bytecodes: compressed_bytecodes.into(),
         }
     }
 
@@ -722,9 +769,13 @@ impl<S: ReadStorage, Tr: Tracer + Default + 'static> VmInterface for Vm<S, Tr> {
         with_compression: bool,
     ) -> (BytecodeCompressionResult<'_>, VmExecutionResultAndLogs) {
         self.push_transaction_inner(tx, 0, with_compression);
+        let is_rejected = self.rejected_tx_halt.is_some();
         let result = self.inspect(tracer, InspectExecutionMode::OneTx);
 
-        let compression_result = if self.has_unpublished_bytecodes() {
+        let compression_result = if is_rejected {
+            // The transaction wasn't pushed, so none of its bytecodes were compressed.
+            Ok((&[][..]).into())
+        } else if self.has_unpublished_bytecodes() {
             Err(BytecodeCompressionError::BytecodeCompressionFailed)
         } else {
             Ok(self
@@ -829,6 +880,7 @@ pub(crate) struct World<S, T> {
     dynamic_bytecodes: DynamicBytecodes,
     program_cache: HashMap<U256, Program<T, Self>>,
     pub(crate) bytecode_cache: HashMap<U256, Vec<u8>>,
+    pub(crate) compressed_bytecode_cache: CompressedBytecodeCache,
 }
 
 impl<S: ReadStorage, T: Tracer> World<S, T> {
@@ -838,6 +890,7 @@ impl<S: ReadStorage, T: Tracer> World<S, T> {
             dynamic_bytecodes: DynamicBytecodes::default(),
             program_cache,
             bytecode_cache: HashMap::default(),
+            compressed_bytecode_cache: CompressedBytecodeCache::default(),
         }
     }
 
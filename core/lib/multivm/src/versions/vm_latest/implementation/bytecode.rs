@@ -1,12 +1,9 @@
 use itertools::Itertools;
-use zksync_types::U256;
+use zksync_types::{H256, U256};
 use zksync_utils::bytecode::hash_bytecode;
 
 use crate::{
-    interface::{
-        storage::{StoragePtr, WriteStorage},
-        CompressedBytecodeInfo,
-    },
+    interface::{storage::WriteStorage, CompressedBytecodeInfo},
     utils::{bytecode, bytecode::bytes_to_be_words},
     vm_latest::Vm,
     HistoryMode,
@@ -38,17 +35,18 @@ pub(crate) fn bytecode_to_factory_dep(bytecode: Vec<u8>) -> (U256, Vec<U256>) {
     (bytecode_hash, bytecode_words)
 }
 
-pub(crate) fn compress_bytecodes<S: WriteStorage>(
+pub(crate) fn compress_bytecodes(
     bytecodes: &[Vec<u8>],
-    storage: StoragePtr<S>,
+    mut is_bytecode_known: impl FnMut(H256) -> bool,
 ) -> Vec<CompressedBytecodeInfo> {
-    bytecodes
+    let unknown_bytecodes: Vec<_> = bytecodes
         .iter()
         .enumerate()
         .sorted_by_key(|(_idx, dep)| *dep)
         .dedup_by(|x, y| x.1 == y.1)
-        .filter(|(_idx, dep)| !storage.borrow_mut().is_bytecode_known(&hash_bytecode(dep)))
+        .filter(|(_idx, dep)| !is_bytecode_known(hash_bytecode(dep)))
         .sorted_by_key(|(idx, _dep)| *idx)
-        .filter_map(|(_idx, dep)| bytecode::compress(dep.clone()).ok())
-        .collect()
+        .map(|(_idx, dep)| dep.clone())
+        .collect();
+    bytecode::compress_bytecodes(unknown_bytecodes)
 }
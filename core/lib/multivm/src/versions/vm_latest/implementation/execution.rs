@@ -7,7 +7,7 @@ use crate::{
     interface::{
         storage::WriteStorage,
         tracer::{TracerExecutionStatus, VmExecutionStopReason},
-        VmExecutionMode, VmExecutionResultAndLogs,
+        ExecutionResult, VmExecutionMode, VmExecutionResultAndLogs,
     },
     vm_latest::{
         old_vm::utils::{vm_may_have_ended_inner, VmExecutionResult},
@@ -27,6 +27,13 @@ impl<S: WriteStorage, H: HistoryMode> Vm<S, H> {
         execution_mode: VmExecutionMode,
         custom_pubdata_tracer: Option<PubdataTracer<S>>,
     ) -> VmExecutionResultAndLogs {
+        // A rejected transaction isn't in the bootloader memory, so there's nothing to execute for it.
+        if let Some(reason) = self.rejected_tx_halt.take() {
+            if let VmExecutionMode::OneTx = execution_mode {
+                return VmExecutionResultAndLogs::mock(ExecutionResult::Halt { reason });
+            }
+        }
+
         let mut enable_refund_tracer = false;
         if let VmExecutionMode::OneTx = execution_mode {
             // Move the pointer to the next transaction
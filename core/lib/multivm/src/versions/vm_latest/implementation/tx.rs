@@ -3,6 +3,7 @@ use zksync_types::{l1::is_l1_tx_type, Transaction};
 
 use crate::{
     interface::storage::WriteStorage,
+    utils::bytecode::validate_bytecodes,
     vm_latest::{
         constants::BOOTLOADER_HEAP_PAGE,
         implementation::bytecode::{bytecode_to_factory_dep, compress_bytecodes},
@@ -20,6 +21,14 @@ impl<S: WriteStorage, H: HistoryMode> Vm<S, H> {
         predefined_refund: u64,
         with_compression: bool,
     ) {
+        self.rejected_tx_halt = None;
+        if self.validate_bytecodes {
+            if let Err(err) = validate_bytecodes(&tx.factory_deps) {
+                self.rejected_tx_halt = Some(err.into_halt());
+                return;
+            }
+        }
+
         let timestamp = Timestamp(self.state.local_state.timestamp);
         let codes_for_decommiter = tx
             .factory_deps
@@ -31,7 +40,13 @@ impl<S: WriteStorage, H: HistoryMode> Vm<S, H> {
             // L1 transactions do not need compression
             vec![]
         } else {
-            compress_bytecodes(&tx.factory_deps, self.state.storage.storage.get_ptr())
+            // Bytecodes published earlier in the batch are treated as known, even if the publishing
+            // transaction isn't executed yet.
+            let storage = self.state.storage.storage.get_ptr();
+            compress_bytecodes(&tx.factory_deps, |hash| {
+                self.bootloader_state.is_bytecode_published(&hash)
+                    || storage.borrow_mut().is_bytecode_known(&hash)
+            })
         };
 
         self.state
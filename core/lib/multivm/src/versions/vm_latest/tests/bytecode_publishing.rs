@@ -1,5 +1,9 @@
 use crate::{
-    versions::testonly::bytecode_publishing::test_bytecode_publishing,
+    versions::testonly::bytecode_publishing::{
+        test_bytecode_publishing, test_bytecode_publishing_is_deduplicated_within_batch,
+        test_malformed_factory_deps_are_dropped_in_bootloader_mode,
+        test_malformed_factory_deps_halt_transaction,
+    },
     vm_latest::{HistoryEnabled, Vm},
 };
 
@@ -7,3 +11,18 @@ use crate::{
 fn bytecode_publishing() {
     test_bytecode_publishing::<Vm<_, HistoryEnabled>>();
 }
+
+#[test]
+fn bytecode_publishing_is_deduplicated_within_batch() {
+    test_bytecode_publishing_is_deduplicated_within_batch::<Vm<_, HistoryEnabled>>();
+}
+
+#[test]
+fn malformed_factory_deps_halt_transaction() {
+    test_malformed_factory_deps_halt_transaction::<Vm<_, HistoryEnabled>>();
+}
+
+#[test]
+fn malformed_factory_deps_are_dropped_in_bootloader_mode() {
+    test_malformed_factory_deps_are_dropped_in_bootloader_mode::<Vm<_, HistoryEnabled>>();
+}
@@ -188,6 +188,10 @@ impl TestedVm for TestedLatestVm {
         let overhead = tx.overhead_gas();
         self.push_raw_transaction(tx, overhead, refund, true)
     }
+
+    fn set_bytecode_validation(&mut self, enabled: bool) {
+        self.set_bytecode_validation(enabled);
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -15,9 +15,9 @@ use crate::{
     interface::{
         storage::{StoragePtr, WriteStorage},
         BytecodeCompressionError, BytecodeCompressionResult, CurrentExecutionState,
-        FinishedL1Batch, L1BatchEnv, L2BlockEnv, PushTransactionResult, SystemEnv, VmExecutionMode,
-        VmExecutionResultAndLogs, VmFactory, VmInterface, VmInterfaceHistoryEnabled,
-        VmTrackingContracts,
+        FinishedL1Batch, Halt, L1BatchEnv, L2BlockEnv, PushTransactionResult, SystemEnv,
+        VmExecutionMode, VmExecutionResultAndLogs, VmFactory, VmInterface,
+        VmInterfaceHistoryEnabled, VmTrackingContracts,
     },
     utils::{bytecode::be_words_to_bytes, events::extract_l2tol1logs_from_l1_messenger},
     vm_latest::{
@@ -78,6 +78,10 @@ pub struct Vm<S: WriteStorage, H: HistoryMode> {
     // Snapshots for the current run
     pub(crate) snapshots: Vec<VmSnapshot>,
     pub(crate) subversion: MultiVMSubversion,
+    /// Whether factory deps of pushed transactions are validated, see [`Self::set_bytecode_validation()`].
+    pub(crate) validate_bytecodes: bool,
+    /// Halt reason of the last pushed transaction if it was rejected instead of being pushed.
+    pub(crate) rejected_tx_halt: Option<Halt>,
     _phantom: std::marker::PhantomData<H>,
 }
 
@@ -149,11 +153,13 @@ impl<S: WriteStorage, H: HistoryMode> VmInterface for Vm<S, H> {
 
     fn push_transaction(&mut self, tx: Transaction) -> PushTransactionResult<'_> {
         self.push_transaction_with_compression(tx, true);
+        let compressed_bytecodes: &[_] = if self.rejected_tx_halt.is_some() {
+            &[]
+        } else {
+            self.bootloader_state.get_last_tx_compressed_bytecodes()
+        };
         PushTransactionResult {
-            compressed_bytecodes: self
-                .bootloader_state
-                .get_last_tx_compressed_bytecodes()
-                .into(),
+            compressed_bytecodes: compressed_bytecodes.into(),
         }
     }
 
@@ -178,8 +184,12 @@ impl<S: WriteStorage, H: HistoryMode> VmInterface for Vm<S, H> {
         with_compression: bool,
     ) -> (BytecodeCompressionResult<'_>, VmExecutionResultAndLogs) {
         self.push_transaction_with_compression(tx, with_compression);
+        let is_rejected = self.rejected_tx_halt.is_some();
         let result = self.inspect_inner(tracer, VmExecutionMode::OneTx, None);
-        if self.has_unpublished_bytecodes() {
+        if is_rejected {
+            // The transaction wasn't pushed, so none of its bytecodes were compressed.
+            (Ok((&[][..]).into()), result)
+        } else if self.has_unpublished_bytecodes() {
             (
                 Err(BytecodeCompressionError::BytecodeCompressionFailed),
                 result,
@@ -258,9 +268,24 @@ impl<S: WriteStorage, H: HistoryMode> Vm<S, H> {
             batch_env,
             subversion,
             snapshots: vec![],
+            validate_bytecodes: false,
+            rejected_tx_halt: None,
             _phantom: Default::default(),
         }
     }
+
+    /// Enables checking that factory deps of pushed transactions are well-formed bytecodes before they are
+    /// hashed and compressed. A transaction with malformed factory deps is not executed; it halts with
+    /// [`Halt::FailedToMarkFactoryDependencies`] naming the malformed bytecode instead.
+    ///
+    /// The halt is only reported if the transaction is executed in the `OneTx` mode right after it's pushed.
+    /// If the VM is run in the batch or bootloader mode instead, the rejected transaction is dropped
+    /// without being reported.
+    ///
+    /// Disabled by default, since factory deps are validated before transactions reach the VM.
+    pub fn set_bytecode_validation(&mut self, enabled: bool) {
+        self.validate_bytecodes = enabled;
+    }
 }
 
 impl<S: WriteStorage> VmInterfaceHistoryEnabled for Vm<S, HistoryEnabled> {
@@ -48,9 +48,34 @@ impl ProtoRepr for proto::DataAvailabilityClient {
             }),
             proto::data_availability_client::Config::Celestia(conf) => Celestia(CelestiaConfig {
                 api_node_url: required(&conf.api_node_url).context("namespace")?.clone(),
-                namespace: required(&conf.namespace).context("namespace")?.clone(),
+                namespace: conf.namespace.clone().unwrap_or_default(),
+                namespaces: conf.namespaces.clone(),
                 chain_id: required(&conf.chain_id).context("chain_id")?.clone(),
                 timeout_ms: *required(&conf.timeout_ms).context("timeout_ms")?,
+                connect_timeout_ms: conf.connect_timeout_ms,
+                submit_timeout_ms: conf.submit_timeout_ms,
+                max_blob_size: conf.max_blob_size.map(|x| x as usize),
+                max_submit_retries: conf.max_submit_retries,
+                submit_retry_backoff_ms: conf.submit_retry_backoff_ms,
+                node_rpc_url: conf.node_rpc_url.clone(),
+                allow_blob_chunking: conf.allow_blob_chunking.unwrap_or_default(),
+                balance_warn_threshold: conf.balance_warn_threshold,
+                balance_poll_interval_ms: conf.balance_poll_interval_ms,
+                tls_ca_cert_path: conf.tls_ca_cert_path.clone(),
+                tls_client_cert_path: conf.tls_client_cert_path.clone(),
+                tls_client_key_path: conf.tls_client_key_path.clone(),
+                confirmation_depth: conf.confirmation_depth.unwrap_or_default(),
+                max_inclusion_wait_ms: conf.max_inclusion_wait_ms,
+                balance_cache_ttl_ms: conf.balance_cache_ttl_ms,
+                max_list_blobs_range: conf.max_list_blobs_range,
+                gas_price_multiplier: conf.gas_price_multiplier,
+                gas_price_escalation_factor: conf.gas_price_escalation_factor,
+                max_gas_price_multiplier: conf.max_gas_price_multiplier,
+                height_wait_timeout_ms: conf.height_wait_timeout_ms,
+                dispatch_dedup_ttl_ms: conf.dispatch_dedup_ttl_ms,
+                submission_log_path: conf.submission_log_path.clone(),
+                inclusion_poll_concurrency: conf.inclusion_poll_concurrency.map(|x| x as usize),
+                fee_granter: conf.fee_granter.clone(),
             }),
             proto::data_availability_client::Config::Eigen(conf) => Eigen(EigenConfig {
                 rpc_node_url: required(&conf.rpc_node_url)
@@ -91,8 +116,33 @@ impl ProtoRepr for proto::DataAvailabilityClient {
                 proto::data_availability_client::Config::Celestia(proto::CelestiaConfig {
                     api_node_url: Some(config.api_node_url.clone()),
                     namespace: Some(config.namespace.clone()),
+                    namespaces: config.namespaces.clone(),
                     chain_id: Some(config.chain_id.clone()),
                     timeout_ms: Some(config.timeout_ms),
+                    connect_timeout_ms: config.connect_timeout_ms,
+                    submit_timeout_ms: config.submit_timeout_ms,
+                    max_blob_size: config.max_blob_size.map(|x| x as u64),
+                    max_submit_retries: config.max_submit_retries,
+                    submit_retry_backoff_ms: config.submit_retry_backoff_ms,
+                    node_rpc_url: config.node_rpc_url.clone(),
+                    allow_blob_chunking: Some(config.allow_blob_chunking),
+                    balance_warn_threshold: config.balance_warn_threshold,
+                    balance_poll_interval_ms: config.balance_poll_interval_ms,
+                    tls_ca_cert_path: config.tls_ca_cert_path.clone(),
+                    tls_client_cert_path: config.tls_client_cert_path.clone(),
+                    tls_client_key_path: config.tls_client_key_path.clone(),
+                    confirmation_depth: Some(config.confirmation_depth),
+                    max_inclusion_wait_ms: config.max_inclusion_wait_ms,
+                    balance_cache_ttl_ms: config.balance_cache_ttl_ms,
+                    max_list_blobs_range: config.max_list_blobs_range,
+                    gas_price_multiplier: config.gas_price_multiplier,
+                    gas_price_escalation_factor: config.gas_price_escalation_factor,
+                    max_gas_price_multiplier: config.max_gas_price_multiplier,
+                    height_wait_timeout_ms: config.height_wait_timeout_ms,
+                    dispatch_dedup_ttl_ms: config.dispatch_dedup_ttl_ms,
+                    submission_log_path: config.submission_log_path.clone(),
+                    inclusion_poll_concurrency: config.inclusion_poll_concurrency.map(|x| x as u64),
+                    fee_granter: config.fee_granter.clone(),
                 })
             }
             Eigen(config) => proto::data_availability_client::Config::Eigen(proto::EigenConfig {
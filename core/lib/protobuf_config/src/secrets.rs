@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{path::PathBuf, str::FromStr};
 
 use anyhow::Context;
 use secrecy::ExposeSecret;
@@ -8,7 +8,11 @@ use zksync_basic_types::{
 };
 use zksync_config::configs::{
     consensus::{AttesterSecretKey, ConsensusSecrets, NodeSecretKey, ValidatorSecretKey},
-    da_client::{avail::AvailSecrets, celestia::CelestiaSecrets, eigen::EigenSecrets},
+    da_client::{
+        avail::AvailSecrets,
+        celestia::{CelestiaPrivateKey, CelestiaSecrets},
+        eigen::EigenSecrets,
+    },
     secrets::{DataAvailabilitySecrets, Secrets},
     DatabaseSecrets, L1Secrets,
 };
@@ -129,9 +133,22 @@ impl ProtoRepr for proto::DataAvailabilitySecrets {
                 })
             }
             DaSecrets::Celestia(celestia) => DataAvailabilitySecrets::Celestia(CelestiaSecrets {
-                private_key: PrivateKey::from_str(
-                    required(&celestia.private_key).context("private_key")?,
+                private_key: CelestiaPrivateKey::from_sources(
+                    celestia
+                        .private_key
+                        .as_deref()
+                        .map(PrivateKey::from_str)
+                        .transpose()
+                        .context("private_key")?,
+                    celestia.private_key_file.as_ref().map(PathBuf::from),
+                    celestia.private_key_env.clone(),
                 )?,
+                node_auth_token: celestia
+                    .node_auth_token
+                    .as_deref()
+                    .map(APIKey::from_str)
+                    .transpose()
+                    .context("node_auth_token")?,
             }),
             DaSecrets::Eigen(eigen) => DataAvailabilitySecrets::Eigen(EigenSecrets {
                 private_key: PrivateKey::from_str(
@@ -180,8 +197,23 @@ impl ProtoRepr for proto::DataAvailabilitySecrets {
                 }))
             }
             DataAvailabilitySecrets::Celestia(config) => {
+                let (private_key, private_key_file, private_key_env) = match &config.private_key {
+                    CelestiaPrivateKey::Inline(key) => {
+                        (Some(key.0.expose_secret().to_string()), None, None)
+                    }
+                    CelestiaPrivateKey::File(path) => {
+                        (None, Some(path.display().to_string()), None)
+                    }
+                    CelestiaPrivateKey::Env(var) => (None, None, Some(var.clone())),
+                };
                 Some(DaSecrets::Celestia(proto::CelestiaSecret {
-                    private_key: Some(config.private_key.0.expose_secret().to_string()),
+                    private_key,
+                    private_key_file,
+                    private_key_env,
+                    node_auth_token: config
+                        .node_auth_token
+                        .as_ref()
+                        .map(|token| token.0.expose_secret().to_string()),
                 }))
             }
             DataAvailabilitySecrets::Eigen(config) => Some(DaSecrets::Eigen(proto::EigenSecret {
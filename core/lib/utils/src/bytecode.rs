@@ -18,8 +18,11 @@ pub enum InvalidBytecodeError {
 }
 
 pub fn validate_bytecode(code: &[u8]) -> Result<(), InvalidBytecodeError> {
-    let bytecode_len = code.len();
+    validate_bytecode_len(code.len())
+}
 
+/// Same as [`validate_bytecode()`], but only needs the bytecode length, e.g. if the bytecode is streamed.
+pub fn validate_bytecode_len(bytecode_len: usize) -> Result<(), InvalidBytecodeError> {
     if bytecode_len > MAX_BYTECODE_LENGTH_BYTES {
         return Err(InvalidBytecodeError::BytecodeTooLong(
             bytecode_len,
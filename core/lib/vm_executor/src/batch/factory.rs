@@ -8,7 +8,7 @@ use zksync_multivm::{
         executor::{BatchExecutor, BatchExecutorFactory},
         pubdata::PubdataBuilder,
         storage::{ReadStorage, StoragePtr, StorageView, StorageViewStats},
-        utils::DivergenceHandler,
+        utils::{DivergenceHandler, ShadowMut},
         BatchTransactionExecutionResult, BytecodeCompressionError, CompressedBytecodeInfo,
         ExecutionResult, FinishedL1Batch, Halt, L1BatchEnv, L2BlockEnv, SystemEnv, VmFactory,
         VmInterface, VmInterfaceHistoryEnabled,
@@ -73,6 +73,8 @@ pub struct MainBatchExecutorFactory<Tr> {
     fast_vm_mode: FastVmMode,
     observe_storage_metrics: bool,
     divergence_handler: Option<DivergenceHandler>,
+    /// Cache of compressed bytecodes shared by the fast VMs of all batches created by this factory.
+    compressed_bytecode_cache: vm_fast::CompressedBytecodeCache,
     _tracer: PhantomData<Tr>,
 }
 
@@ -83,6 +85,7 @@ impl<Tr: BatchTracer> MainBatchExecutorFactory<Tr> {
             fast_vm_mode: FastVmMode::Old,
             observe_storage_metrics: false,
             divergence_handler: None,
+            compressed_bytecode_cache: vm_fast::CompressedBytecodeCache::default(),
             _tracer: PhantomData,
         }
     }
@@ -128,6 +131,7 @@ impl<S: ReadStorage + Send + 'static, Tr: BatchTracer> BatchExecutorFactory<S>
             fast_vm_mode: self.fast_vm_mode,
             observe_storage_metrics: self.observe_storage_metrics,
             divergence_handler: self.divergence_handler.clone(),
+            compressed_bytecode_cache: self.compressed_bytecode_cache.clone(),
             commands: commands_receiver,
             _storage: PhantomData,
             _tracer: PhantomData::<Tr>,
@@ -258,6 +262,7 @@ struct CommandReceiver<S, Tr> {
     fast_vm_mode: FastVmMode,
     observe_storage_metrics: bool,
     divergence_handler: Option<DivergenceHandler>,
+    compressed_bytecode_cache: vm_fast::CompressedBytecodeCache,
     commands: mpsc::Receiver<Command>,
     _storage: PhantomData<S>,
     _tracer: PhantomData<Tr>,
@@ -283,9 +288,21 @@ impl<S: ReadStorage + 'static, Tr: BatchTracer> CommandReceiver<S, Tr> {
         let mut batch_finished = false;
         let mut prev_storage_stats = StorageViewStats::default();
 
-        if let BatchVm::Fast(FastVmInstance::Shadowed(shadowed)) = &mut vm {
-            if let Some(handler) = self.divergence_handler.take() {
-                shadowed.set_divergence_handler(handler);
+        match &mut vm {
+            BatchVm::Legacy(_) => { /* the legacy VM doesn't cache compressed bytecodes */ }
+            BatchVm::Fast(FastVmInstance::Fast(fast_vm)) => {
+                fast_vm.set_compressed_bytecode_cache(self.compressed_bytecode_cache.clone());
+            }
+            BatchVm::Fast(FastVmInstance::Shadowed(shadowed)) => {
+                if let Some(handler) = self.divergence_handler.take() {
+                    shadowed.set_divergence_handler(handler);
+                }
+                shadowed.get_mut("set_compressed_bytecode_cache", |vm| {
+                    if let ShadowMut::Shadow(fast_vm) = vm {
+                        fast_vm
+                            .set_compressed_bytecode_cache(self.compressed_bytecode_cache.clone());
+                    }
+                });
             }
         }
 
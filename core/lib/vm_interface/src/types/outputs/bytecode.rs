@@ -1,5 +1,95 @@
+use std::{iter, ops};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct CompressedBytecodeInfo {
     pub original: Vec<u8>,
     pub compressed: Vec<u8>,
+    /// Algorithm that produced `compressed`.
+    pub version: BytecodeCompressionVersion,
+}
+
+/// Algorithm (and its revision) used to compress a bytecode.
+///
+/// The compressed bytes published on L1 are untagged, since their format is fixed by the L1 contracts;
+/// the version is only recorded alongside them, e.g. in [`CompressedBytecodeInfo::tagged_compressed()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BytecodeCompressionVersion {
+    /// Dictionary of unique 8-byte chunks followed by 2-byte chunk indices.
+    V1,
+}
+
+impl BytecodeCompressionVersion {
+    /// Returns the tag byte identifying this version.
+    pub const fn tag(self) -> u8 {
+        match self {
+            Self::V1 => 1,
+        }
+    }
+
+    /// Parses the tag byte produced by [`Self::tag()`].
+    pub fn from_tag(tag: u8) -> Result<Self, UnknownBytecodeCompressionVersion> {
+        match tag {
+            1 => Ok(Self::V1),
+            _ => Err(UnknownBytecodeCompressionVersion(tag)),
+        }
+    }
+}
+
+/// Error returned when a compressed bytecode is tagged with a version this code doesn't know.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("unknown bytecode compression version: {0}")]
+pub struct UnknownBytecodeCompressionVersion(pub u8);
+
+impl CompressedBytecodeInfo {
+    /// Returns the compressed bytecode prefixed with its version tag, so that it can be stored and decompressed
+    /// with the matching algorithm later.
+    pub fn tagged_compressed(&self) -> Vec<u8> {
+        let mut tagged = Vec::with_capacity(self.compressed.len() + 1);
+        tagged.push(self.version.tag());
+        tagged.extend_from_slice(&self.compressed);
+        tagged
+    }
+
+    /// Returns the sizes of the bytecode before and after compression.
+    pub fn compression_stats(&self) -> BytecodeCompressionStats {
+        BytecodeCompressionStats {
+            original_len: self.original.len(),
+            compressed_len: self.compressed.len(),
+        }
+    }
+}
+
+/// Sizes of one or more bytecodes before and after compression. Stats for several bytecodes can be summed up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BytecodeCompressionStats {
+    pub original_len: usize,
+    pub compressed_len: usize,
+}
+
+impl BytecodeCompressionStats {
+    /// Returns the ratio of the compressed length to the original one (i.e., lower is better).
+    /// Returns 1 if there are no bytecodes.
+    pub fn ratio(&self) -> f64 {
+        if self.original_len == 0 {
+            return 1.0;
+        }
+        self.compressed_len as f64 / self.original_len as f64
+    }
+}
+
+impl ops::Add for BytecodeCompressionStats {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            original_len: self.original_len + other.original_len,
+            compressed_len: self.compressed_len + other.compressed_len,
+        }
+    }
+}
+
+impl iter::Sum for BytecodeCompressionStats {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), ops::Add::add)
+    }
 }
@@ -1,7 +1,10 @@
 use std::borrow::Cow;
 
 pub use self::{
-    bytecode::CompressedBytecodeInfo,
+    bytecode::{
+        BytecodeCompressionStats, BytecodeCompressionVersion, CompressedBytecodeInfo,
+        UnknownBytecodeCompressionVersion,
+    },
     execution_result::{
         BatchTransactionExecutionResult, Call, CallType, ExecutionResult,
         OneshotTransactionExecutionResult, Refunds, TransactionExecutionResult, TxExecutionStatus,
@@ -1,101 +1,1506 @@
 use std::{
-    fmt::{Debug, Formatter},
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    fs,
+    future::Future,
+    io::{self, Write},
+    path::PathBuf,
     str::FromStr,
-    sync::Arc,
-    time,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex, PoisonError, Weak,
+    },
+    time::{Duration, Instant},
 };
 
+use anyhow::Context;
 use async_trait::async_trait;
 use celestia_types::{blob::Commitment, nmt::Namespace, Blob};
-use serde::{Deserialize, Serialize};
+use futures::StreamExt;
+use serde::Deserialize;
+use sha2::Digest;
 use subxt_signer::ExposeSecret;
-use tonic::transport::Endpoint;
-use zksync_config::configs::da_client::celestia::{CelestiaConfig, CelestiaSecrets};
+use tonic::transport::{Certificate, ClientTlsConfig, Endpoint, Identity};
+use zksync_config::configs::da_client::celestia::{CelestiaConfig, CelestiaSecrets, MAX_BLOB_SIZE};
 use zksync_da_client::{
-    types::{DAError, DispatchResponse, InclusionData},
+    types::{DAError, DispatchResponse, InclusionData, InclusionStatus},
     DataAvailabilityClient,
 };
 
 use crate::{
-    celestia::sdk::{BlobTxHash, RawCelestiaClient},
-    utils::to_non_retriable_da_error,
+    celestia::{
+        metrics::{namespace_label, METRICS},
+        retry::{retry_da, RetryPolicy},
+        sdk::{
+            sparse_shares_needed, BlobTxHash, CelestiaNodeClient, CelestiaSdkError, CelestiaSigner,
+            GasPricePolicy, LocalSigner, RawCelestiaClient, BYTES_PER_BLOB_INFO, SHARE_SIZE,
+        },
+        tendermint::types::BlobTx,
+    },
+    utils::{to_non_retriable_da_error, to_retriable_da_error},
 };
 
+/// Celestia's transaction size limit (2 MiB since app version 3), used to split [`CelestiaClient::dispatch_blobs()`]
+/// into transactions.
+const MAX_TX_BYTES: usize = 2 * 1024 * 1024;
+/// The share of the transaction size limit that [`pack_blobs`] aims to fill, in percent. Leaves
+/// some headroom for the transaction envelope (signatures, fee, etc.).
+const TARGET_TX_UTILIZATION_PERCENT: usize = 90;
+
+/// Maximum number of blobs whose inclusion polling is tracked against `max_inclusion_wait`.
+const MAX_TRACKED_INCLUSIONS: usize = 10_000;
+
+/// Maximum number of recent dispatches remembered for deduplication, see `dispatch_dedup_ttl_ms`.
+const MAX_DEDUPLICATED_DISPATCHES: usize = 1_000;
+
+/// Bounds of the delay between inclusion polls in [`CelestiaClient::submit_and_wait()`].
+const SUBMIT_AND_WAIT_MIN_POLL_DELAY: Duration = Duration::from_secs(1);
+const SUBMIT_AND_WAIT_MAX_POLL_DELAY: Duration = Duration::from_secs(30);
+
+/// Time given to each backend to respond to a health check.
+const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How often the chain head is polled while waiting for a height in [`CelestiaClient::dispatch_blob_after_height()`].
+const HEIGHT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A backend the client depends on. Used as the context of health check errors, so the failed backend can be
+/// determined by downcasting the error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CelestiaBackend {
+    /// gRPC endpoint of the consensus node, used to submit blobs.
+    Grpc,
+    /// celestia-node JSON-RPC API, used to read blobs and the balance.
+    NodeRpc,
+}
+
+impl fmt::Display for CelestiaBackend {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Grpc => f.write_str("Celestia gRPC endpoint is unhealthy"),
+            Self::NodeRpc => f.write_str("celestia-node RPC endpoint is unhealthy"),
+        }
+    }
+}
+
 /// An implementation of the `DataAvailabilityClient` trait that interacts with the Avail network.
 #[derive(Clone)]
 pub struct CelestiaClient {
     config: CelestiaConfig,
+    /// Parsed namespaces from the config, the first one is used by default.
+    namespaces: Vec<Namespace>,
     client: Arc<RawCelestiaClient>,
+    node_client: Option<Arc<CelestiaNodeClient>>,
+    inclusion_tracker: Arc<InclusionTracker>,
+    /// Shared between clones, so that all of them submit from the account one at a time.
+    submission_lock: Arc<SubmissionLock>,
+    /// The last fetched balance together with the time it was fetched, shared between clones.
+    cached_balance: Arc<Mutex<Option<(u64, Instant)>>>,
+    /// Recently dispatched payloads, shared between clones. Only set if `dispatch_dedup_ttl_ms` is configured.
+    dispatch_dedup: Option<Arc<DispatchDedupCache>>,
+    /// Only set if `submission_log_path` is configured.
+    submission_log: Option<Arc<SubmissionLog>>,
 }
 
 impl CelestiaClient {
     pub async fn new(config: CelestiaConfig, secrets: CelestiaSecrets) -> anyhow::Result<Self> {
-        let grpc_channel = Endpoint::from_str(config.api_node_url.clone().as_str())?
-            .timeout(time::Duration::from_millis(config.timeout_ms))
-            .connect()
-            .await?;
+        let private_key = secrets.private_key.resolve()?;
+        let signer = LocalSigner::new(private_key.0.expose_secret())?;
+        Self::with_signer(config, secrets, Arc::new(signer)).await
+    }
+
+    /// Creates a client that signs transactions with the provided signer, e.g. one backed by an HSM or KMS.
+    /// The private key in `secrets` is not used.
+    pub async fn with_signer(
+        config: CelestiaConfig,
+        secrets: CelestiaSecrets,
+        signer: Arc<dyn CelestiaSigner>,
+    ) -> anyhow::Result<Self> {
+        // Among other things, checks that `max_blob_size` fits into a transaction.
+        config.validate()?;
+
+        // Namespaces are parsed once, so that a misconfiguration is reported on startup rather than on dispatch.
+        let namespaces = parse_namespaces(&config.namespaces())?;
+
+        // The channel is connected lazily and re-establishes the connection on the next request
+        // if it's dropped, e.g. because the node was restarted.
+        let mut endpoint = Endpoint::from_str(config.api_node_url.clone().as_str())?;
+        if let Some(tls_config) = tls_config(&config)? {
+            endpoint = endpoint
+                .tls_config(tls_config)
+                .context("invalid TLS configuration for the gRPC endpoint")?;
+        }
+        let grpc_channel = endpoint
+            .connect_timeout(config.connect_timeout())
+            .timeout(config.submit_timeout())
+            .connect_lazy();
+
+        let client = RawCelestiaClient::new(
+            grpc_channel,
+            signer,
+            config.chain_id.clone(),
+            GasPricePolicy::from_config(&config),
+            config.fee_granter.clone(),
+        )
+        .context("could not create Celestia client")?;
 
-        let private_key = secrets.private_key.0.expose_secret().to_string();
-        let client = RawCelestiaClient::new(grpc_channel, private_key, config.chain_id.clone())
-            .expect("could not create Celestia client");
+        let node_client = config.node_rpc_url.clone().map(|url| {
+            let auth_token = secrets
+                .node_auth_token
+                .as_ref()
+                .map(|token| token.0.expose_secret().to_string());
+            Arc::new(CelestiaNodeClient::new(url, auth_token))
+        });
+
+        if let Some(threshold) = config.balance_warn_threshold {
+            let node_client = node_client
+                .as_ref()
+                .context("node_rpc_url must be configured to monitor the balance")?;
+            tokio::spawn(monitor_balance(
+                Arc::downgrade(node_client),
+                client.address().to_string(),
+                threshold,
+                config.balance_poll_interval(),
+            ));
+        }
 
         Ok(Self {
+            dispatch_dedup: DispatchDedupCache::from_config(&config),
+            submission_log: config
+                .submission_log_path
+                .as_ref()
+                .map(|path| Arc::new(SubmissionLog::new(path.into()))),
             config,
+            namespaces,
             client: Arc::new(client),
+            node_client,
+            inclusion_tracker: Arc::default(),
+            submission_lock: Arc::default(),
+            cached_balance: Arc::default(),
         })
     }
-}
-#[derive(Serialize, Deserialize)]
-pub struct BlobId {
-    pub commitment: Commitment,
-    pub height: u64,
-}
 
-#[async_trait]
-impl DataAvailabilityClient for CelestiaClient {
-    async fn dispatch_blob(
+    /// Returns a copy of the client that submits blobs to the chain with the given ID, e.g. to iterate over networks
+    /// served by the same endpoints.
+    ///
+    /// The gRPC and celestia-node connections, the signer and the rest of the config are reused as is, so they
+    /// must be valid for the new chain; if it's served by other endpoints, create a new client instead.
+    /// The cached balance, the inclusion polling history and the recent dispatches are not shared with
+    /// the original client.
+    /// The background balance monitor, if any, keeps running for the original client only. Submissions are
+    /// not recorded in the submission log, since blob IDs don't specify the chain.
+    pub fn with_chain_id(&self, chain_id: String) -> Self {
+        Self {
+            config: CelestiaConfig {
+                chain_id: chain_id.clone(),
+                ..self.config.clone()
+            },
+            namespaces: self.namespaces.clone(),
+            client: Arc::new(self.client.with_chain_id(chain_id)),
+            node_client: self.node_client.clone(),
+            inclusion_tracker: Arc::default(),
+            submission_lock: Arc::default(),
+            cached_balance: Arc::default(),
+            dispatch_dedup: DispatchDedupCache::from_config(&self.config),
+            submission_log: None,
+        }
+    }
+
+    /// Checks that the backends are reachable, i.e. the gRPC endpoint and, if configured, the celestia-node RPC.
+    ///
+    /// On failure, the error context is the [`CelestiaBackend`] that failed.
+    pub async fn check_health(&self) -> Result<(), DAError> {
+        Self::check_backend(CelestiaBackend::Grpc, self.client.ping()).await?;
+        if let Some(node_client) = &self.node_client {
+            Self::check_backend(CelestiaBackend::NodeRpc, node_client.ping()).await?;
+        }
+        Ok(())
+    }
+
+    async fn check_backend(
+        backend: CelestiaBackend,
+        check: impl Future<Output = anyhow::Result<()>>,
+    ) -> Result<(), DAError> {
+        let result = match tokio::time::timeout(HEALTH_CHECK_TIMEOUT, check).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow::anyhow!(
+                "no response within {HEALTH_CHECK_TIMEOUT:?}"
+            )),
+        };
+        result.context(backend).map_err(to_retriable_da_error)
+    }
+
+    /// Returns the balance of the account paying for the blobs, in `utia`.
+    /// Requires `node_rpc_url` to be configured.
+    ///
+    /// The balance is cached for `balance_cache_ttl_ms`, so it may be slightly outdated; use [`Self::balance_uncached()`]
+    /// if the latest value is required.
+    pub async fn balance(&self) -> Result<u64, DAError> {
+        let cached_balance = *self
+            .cached_balance
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some((balance, fetched_at)) = cached_balance {
+            if fetched_at.elapsed() < self.config.balance_cache_ttl() {
+                return Ok(balance);
+            }
+        }
+        self.balance_uncached().await
+    }
+
+    /// Same as [`Self::balance()`], but always fetches the balance from the node.
+    pub async fn balance_uncached(&self) -> Result<u64, DAError> {
+        let node_client = self.node_client.as_ref().ok_or_else(|| {
+            to_non_retriable_da_error(anyhow::anyhow!(
+                "node_rpc_url must be configured to query the balance"
+            ))
+        })?;
+        let balance = retry_da(RetryPolicy::QUERY, "fetch balance", || async {
+            node_client
+                .balance_for_address(self.client.address())
+                .await
+                .map_err(to_retriable_da_error)
+        })
+        .await?;
+        METRICS.balance.set(balance);
+        *self
+            .cached_balance
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some((balance, Instant::now()));
+        Ok(balance)
+    }
+
+    /// Fetches the data of a previously dispatched blob, verifying that it matches the commitment in `blob_id`.
+    /// If the payload was split into chunks, all of them are fetched, verified and reassembled.
+    ///
+    /// Returns `None` if the node doesn't have the blob anymore, e.g. because the height was pruned.
+    /// Requires `node_rpc_url` to be configured.
+    pub async fn get_blob_data(&self, blob_id: &str) -> Result<Option<Vec<u8>>, DAError> {
+        let node_client = self.node_client.as_ref().ok_or_else(|| {
+            to_non_retriable_da_error(anyhow::anyhow!(
+                "node_rpc_url must be configured to read blobs back"
+            ))
+        })?;
+        let blob_id = blob_id
+            .parse::<BlobId>()
+            .map_err(to_non_retriable_da_error)?;
+
+        // Legacy blob IDs don't specify the namespace, so all configured namespaces are checked.
+        let namespaces = match blob_id.namespace {
+            Some(namespace) => vec![namespace],
+            None => self.namespaces.clone(),
+        };
+        if blob_id.chunks.is_empty() {
+            return Self::fetch_blob(node_client, &namespaces, blob_id.height, blob_id.commitment)
+                .await;
+        }
+
+        let mut data = vec![];
+        for chunk in &blob_id.chunks {
+            let Some(chunk_data) =
+                Self::fetch_blob(node_client, &namespaces, blob_id.height, chunk.commitment)
+                    .await?
+            else {
+                return Ok(None);
+            };
+            if chunk_data.len() != chunk.len {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "chunk at offset {} has length {}, while {} is expected",
+                    chunk.offset,
+                    chunk_data.len(),
+                    chunk.len
+                )));
+            }
+            data.extend_from_slice(&chunk_data);
+        }
+        Ok(Some(data))
+    }
+
+    /// Fetches a single blob from the first namespace that has it, verifying its commitment.
+    async fn fetch_blob(
+        node_client: &CelestiaNodeClient,
+        namespaces: &[Namespace],
+        height: u64,
+        commitment: Commitment,
+    ) -> Result<Option<Vec<u8>>, DAError> {
+        for &namespace in namespaces {
+            let Some(blob) = node_client
+                .get_blob(height, namespace, commitment)
+                .await
+                .map_err(to_retriable_da_error)?
+            else {
+                continue;
+            };
+
+            let actual_commitment = Blob::new(namespace, blob.data.clone())
+                .map_err(to_non_retriable_da_error)?
+                .commitment;
+            if actual_commitment != commitment {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "commitment of the blob returned by the node doesn't match the blob ID, height: {height}"
+                )));
+            }
+            return Ok(Some(blob.data));
+        }
+
+        Ok(None)
+    }
+
+    /// Lists the blobs posted to the namespace with the given index in the configured `namespaces`
+    /// at heights `from_height..=to_height`, ordered by height.
+    ///
+    /// The returned blob IDs have an empty transaction hash, since the node doesn't report it. Chunks of a split
+    /// payload are listed as separate blobs. The range may span at most `max_list_blobs_range` heights.
+    /// Requires `node_rpc_url` to be configured.
+    pub async fn list_blobs(
         &self,
-        _: u32, // batch number
+        namespace_index: usize,
+        from_height: u64,
+        to_height: u64,
+    ) -> Result<Vec<BlobId>, DAError> {
+        let node_client = self.node_client.as_ref().ok_or_else(|| {
+            to_non_retriable_da_error(anyhow::anyhow!(
+                "node_rpc_url must be configured to list blobs"
+            ))
+        })?;
+        let namespace = self.namespace(namespace_index)?;
+        if from_height > to_height {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "invalid height range: {from_height} > {to_height}"
+            )));
+        }
+        let max_range = self.config.max_list_blobs_range();
+        let range_len = to_height - from_height + 1;
+        if range_len > max_range {
+            return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "height range {from_height}..={to_height} spans {range_len} heights, while at most {max_range} are allowed"
+            )));
+        }
+
+        let mut blob_ids = vec![];
+        for height in from_height..=to_height {
+            let blobs = node_client
+                .get_all_blobs(height, namespace)
+                .await
+                .map_err(to_retriable_da_error)?;
+            blob_ids.extend(blobs.into_iter().map(|blob| BlobId {
+                namespace: Some(namespace),
+                commitment: blob.commitment,
+                height,
+                tx_hash: String::new(),
+                chunks: vec![],
+            }));
+        }
+        Ok(blob_ids)
+    }
+
+    /// Dispatches a blob to the namespace with the given index in the configured `namespaces`.
+    #[tracing::instrument(
+        skip(self, data),
+        fields(
+            namespace = tracing::field::Empty,
+            height = tracing::field::Empty,
+            blob_id = tracing::field::Empty,
+        )
+    )]
+    pub async fn dispatch_blob_to_namespace(
+        &self,
+        namespace_index: usize,
         data: Vec<u8>,
     ) -> Result<DispatchResponse, DAError> {
-        let namespace_bytes =
-            hex::decode(&self.config.namespace).map_err(to_non_retriable_da_error)?;
-        let namespace =
-            Namespace::new_v0(namespace_bytes.as_slice()).map_err(to_non_retriable_da_error)?;
-        let blob = Blob::new(namespace, data).map_err(to_non_retriable_da_error)?;
+        self.check_payload_size(data.len())?;
+        let span = tracing::Span::current();
+        let namespace = self.namespace(namespace_index)?;
+        span.record("namespace", hex::encode(namespace.as_bytes()).as_str());
+
+        let dedup_key = match &self.dispatch_dedup {
+            Some(dedup) => {
+                let key = DispatchDedupCache::key(&namespace, &data);
+                if let Some(blob_id) = dedup.get(&key) {
+                    tracing::info!(%blob_id, "data was dispatched recently, reusing its blob ID");
+                    return Ok(DispatchResponse { blob_id });
+                }
+                Some(key)
+            }
+            None => None,
+        };
+
+        let data_len = data.len();
+        let (blobs, blob_id) = self.build_blobs(namespace, data)?;
+        span.record("blob_id", blob_id.short_id().as_str());
+        tracing::debug!(
+            data_len,
+            blob_count = blobs.len(),
+            commitment = %hex::encode(blob_id.commitment.0),
+            "prepared blobs"
+        );
+
+        let (height, mut responses) = self
+            .submit_and_log(namespace, blobs, vec![blob_id])
+            .await
+            .inspect_err(|err| tracing::warn!(err = %err.error, "failed to submit blobs"))?;
+        span.record("height", height);
+        tracing::info!("submitted blobs");
+
+        let response = responses.pop().expect("one response per blob ID");
+        if let (Some(dedup), Some(key)) = (&self.dispatch_dedup, dedup_key) {
+            dedup.insert(key, response.blob_id.clone());
+        }
+        Ok(response)
+    }
+
+    /// Submits the blobs in a single transaction and records the submission, see [`Self::record_submission()`].
+    /// Returns the height at which the transaction was included, together with the responses.
+    async fn submit_and_log(
+        &self,
+        namespace: Namespace,
+        blobs: Vec<Blob>,
+        blob_ids: Vec<BlobId>,
+    ) -> Result<(u64, Vec<DispatchResponse>), DAError> {
+        let (height, tx_hash) = self.submit_blobs(namespace, blobs).await?;
+        tracing::debug!(%tx_hash, "blobs included in transaction");
+        Ok((
+            height,
+            self.record_submission(blob_ids, height, tx_hash).await,
+        ))
+    }
+
+    /// Completes the blob IDs of a submitted transaction with its height and hash, and records them in
+    /// the submission log, if it's configured. Returns the responses in the order of `blob_ids`.
+    async fn record_submission(
+        &self,
+        blob_ids: Vec<BlobId>,
+        height: u64,
+        tx_hash: String,
+    ) -> Vec<DispatchResponse> {
+        let responses: Vec<_> = blob_ids
+            .into_iter()
+            .map(|blob_id| {
+                BlobId {
+                    height,
+                    tx_hash: tx_hash.clone(),
+                    ..blob_id
+                }
+                .to_dispatch_response()
+            })
+            .collect();
+        if let Some(log) = &self.submission_log {
+            let blob_ids: Vec<_> = responses
+                .iter()
+                .map(|response| &*response.blob_id)
+                .collect();
+            // The blobs are already paid for, so failing the dispatch would only make the caller submit them again.
+            if let Err(err) = log.append(&blob_ids).await {
+                tracing::error!("failed to record submission: {err:#}");
+            }
+        }
+        responses
+    }
+
+    /// Prepares a transaction combining blobs built elsewhere, e.g. by other rollups sharing a sequencer,
+    /// with a blob for `data` in the default namespace, so that the transaction fee is paid once by this client's
+    /// account. Submit the result with [`Self::submit_prepared()`].
+    ///
+    /// The transaction is signed with the current sequence number of the account, so it must be submitted
+    /// before any other dispatch from the account; otherwise the submission fails with a retriable error
+    /// and the transaction has to be prepared again. Only `data` is checked against the blob size limit.
+    pub async fn prepare_combined(
+        &self,
+        external_blobs: Vec<Blob>,
+        data: Vec<u8>,
+    ) -> Result<PreparedBlobTx, DAError> {
+        self.check_payload_size(data.len())?;
+        let namespace = self.namespace(0)?;
+        let (mut blobs, blob_id) = self.build_blobs(namespace, data)?;
+        let external_blob_count = external_blobs.len();
+        blobs.extend(external_blobs);
 
-        let commitment = blob.commitment;
         let blob_tx = self
             .client
-            .prepare(vec![blob])
+            .prepare(blobs, 0)
             .await
-            .map_err(to_non_retriable_da_error)?;
+            .map_err(classify_submit_error)?;
+        let tx_hash = BlobTxHash::compute(&blob_tx);
+        tracing::debug!(%tx_hash, external_blob_count, "prepared combined blob transaction");
+        Ok(PreparedBlobTx {
+            blob_tx,
+            tx_hash,
+            blob_id,
+        })
+    }
 
-        let blob_tx_hash = BlobTxHash::compute(&blob_tx);
+    /// Submits a transaction prepared by [`Self::prepare_combined()`] and returns the dispatch response
+    /// for this client's blob. The blobs of the other parties are included at the same height.
+    ///
+    /// Unlike [`Self::dispatch_blob_to_namespace()`], a failed submission is not retried.
+    pub async fn submit_prepared(
+        &self,
+        prepared: PreparedBlobTx,
+    ) -> Result<DispatchResponse, DAError> {
+        let PreparedBlobTx {
+            blob_tx,
+            tx_hash,
+            blob_id,
+        } = prepared;
+        let namespace = namespace_label(&self.namespaces, blob_id.namespace);
+        let latency = METRICS.submit_latency[&namespace].start();
+        let hex_tx_hash = tx_hash.clone().hex();
         let height = self
-            .client
-            .submit(blob_tx_hash, blob_tx)
+            .submission_lock
+            .run(self.client.submit(tx_hash, blob_tx))
             .await
+            .map_err(|err| self.submit_error(err))
+            .inspect_err(|err| METRICS.observe_error(err, Some(namespace)))?;
+        latency.observe();
+
+        let mut responses = self
+            .record_submission(vec![blob_id], height, hex_tx_hash)
+            .await;
+        Ok(responses.pop().expect("one response per blob ID"))
+    }
+
+    /// Returns the blob IDs recorded in the submission log (`submission_log_path`) in the order of submission,
+    /// so that blobs submitted right before a crash can be reconciled with the caller's state on startup.
+    ///
+    /// Entries are never removed from the log, so the result includes blobs the caller has already persisted.
+    /// Malformed entries, e.g. a line torn by the crash, are skipped.
+    pub async fn recover_pending(&self) -> Result<Vec<BlobId>, DAError> {
+        let log = self
+            .submission_log
+            .clone()
+            .context("submission_log_path is not configured")
             .map_err(to_non_retriable_da_error)?;
+        tokio::task::spawn_blocking(move || log.read())
+            .await
+            .context("reading the submission log panicked")
+            .and_then(|result| result)
+            .map_err(to_non_retriable_da_error)
+    }
+
+    /// Polls several blobs for inclusion concurrently, with up to `inclusion_poll_concurrency` queries in flight,
+    /// e.g. to reconcile a backlog of dispatched batches. Results are returned in the order of `blob_ids`;
+    /// an error for one blob doesn't affect the others.
+    pub async fn poll_many(
+        &self,
+        blob_ids: &[String],
+    ) -> Vec<Result<Option<InclusionData>, DAError>> {
+        futures::stream::iter(blob_ids)
+            .map(|blob_id| self.get_inclusion_data(blob_id))
+            .buffered(self.config.inclusion_poll_concurrency())
+            .collect()
+            .await
+    }
 
-        let blob_id = BlobId { commitment, height };
-        let blob_bytes = bincode::serialize(&blob_id).map_err(to_non_retriable_da_error)?;
+    /// Dispatches a blob for the batch and waits until its inclusion is complete, returning the inclusion data.
+    ///
+    /// Inclusion is polled with an exponential backoff; retriable polling errors are logged and polling continues,
+    /// while other errors are returned right away. A blob whose inclusion failed is reported right away with
+    /// a non-retriable error. If inclusion doesn't complete within `timeout` after dispatching, a retriable error
+    /// with the blob ID is returned; the blob may still get included later.
+    pub async fn submit_and_wait(
+        &self,
+        batch: u32,
+        data: Vec<u8>,
+        timeout: Duration,
+    ) -> Result<InclusionData, DAError> {
+        let blob_id = self.dispatch_blob(batch, data).await?.blob_id;
+        let wait = async {
+            let mut delay = SUBMIT_AND_WAIT_MIN_POLL_DELAY;
+            loop {
+                match self.get_inclusion_status(&blob_id).await {
+                    Ok(InclusionStatus::Included(inclusion_data)) => return Ok(inclusion_data),
+                    Ok(InclusionStatus::Pending) => {}
+                    Ok(InclusionStatus::Failed(reason)) => {
+                        return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                            "inclusion of blob {blob_id} failed: {reason}"
+                        )));
+                    }
+                    Err(err) if err.is_retriable => {
+                        tracing::debug!(%blob_id, err = %err.error, "failed to poll inclusion")
+                    }
+                    Err(err) => return Err(err),
+                }
+                tokio::time::sleep(delay).await;
+                delay = (delay * 2).min(SUBMIT_AND_WAIT_MAX_POLL_DELAY);
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.map_err(|_| {
+            to_retriable_da_error(anyhow::anyhow!(
+                "blob {blob_id} for batch {batch} wasn't included within {timeout:?}"
+            ))
+        })?
+    }
 
-        Ok(DispatchResponse {
-            blob_id: hex::encode(&blob_bytes),
+    /// Waits until the chain reaches `min_height` and then dispatches a blob to the default namespace, e.g. to
+    /// coordinate submissions in integration tests. The blob is included at a height above `min_height`,
+    /// which is reported in the blob ID as usual.
+    ///
+    /// Returns a retriable error if the height isn't reached within `height_wait_timeout_ms`.
+    pub async fn dispatch_blob_after_height(
+        &self,
+        data: Vec<u8>,
+        min_height: u64,
+    ) -> Result<DispatchResponse, DAError> {
+        self.wait_for_height(min_height).await?;
+        self.dispatch_blob_to_namespace(0, data).await
+    }
+
+    async fn wait_for_height(&self, min_height: u64) -> Result<(), DAError> {
+        let timeout = self.config.height_wait_timeout();
+        let wait = async {
+            loop {
+                match self.client.latest_height().await {
+                    Ok(height) if height >= min_height => return,
+                    Ok(height) => tracing::debug!(height, min_height, "waiting for height"),
+                    // The head is polled until the timeout anyway, so errors are only logged.
+                    Err(err) => tracing::debug!(%err, "failed to get latest height"),
+                }
+                tokio::time::sleep(HEIGHT_POLL_INTERVAL).await;
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.map_err(|_| {
+            to_retriable_da_error(anyhow::anyhow!(
+                "chain didn't reach height {min_height} within {timeout:?}"
+            ))
         })
     }
 
-    async fn get_inclusion_data(&self, _: &str) -> Result<Option<InclusionData>, DAError> {
+    /// Computes the blob ID the data would get if dispatched to the default namespace, without submitting it.
+    ///
+    /// The returned ID has zero height and an empty transaction hash, since these are only known after submission.
+    pub async fn compute_blob_id(&self, data: Vec<u8>) -> Result<BlobId, DAError> {
+        let namespace = self.namespace(0)?;
+        let (_, blob_id) = self.build_blobs(namespace, data)?;
+        Ok(blob_id)
+    }
+
+    fn namespace(&self, namespace_index: usize) -> Result<Namespace, DAError> {
+        self.namespaces
+            .get(namespace_index)
+            .copied()
+            .ok_or_else(|| {
+                let namespace_count = self.namespaces.len();
+                to_non_retriable_da_error(anyhow::anyhow!(
+                    "namespace index {namespace_index} is out of range, \
+                     {namespace_count} namespaces are configured"
+                ))
+            })
+    }
+
+    /// Builds the blobs for the data, together with a blob ID lacking the height and transaction hash.
+    ///
+    /// If chunking is allowed, data larger than the blob size limit is split into blobs of at most
+    /// `max_blob_size` bytes, which are meant to be submitted in a single transaction.
+    fn build_blobs(
+        &self,
+        namespace: Namespace,
+        data: Vec<u8>,
+    ) -> Result<(Vec<Blob>, BlobId), DAError> {
+        let max_blob_size = self.max_blob_size();
+        if !self.config.allow_blob_chunking || data.len() <= max_blob_size {
+            let blob = Blob::new(namespace, data).map_err(to_non_retriable_da_error)?;
+            let blob_id = BlobId {
+                namespace: Some(namespace),
+                commitment: blob.commitment,
+                height: 0,
+                tx_hash: String::new(),
+                chunks: vec![],
+            };
+            return Ok((vec![blob], blob_id));
+        }
+
+        let mut chunks = vec![];
+        let mut blobs = vec![];
+        for (idx, chunk_data) in data.chunks(max_blob_size).enumerate() {
+            let blob =
+                Blob::new(namespace, chunk_data.to_vec()).map_err(to_non_retriable_da_error)?;
+            chunks.push(BlobChunk {
+                commitment: blob.commitment,
+                offset: idx * max_blob_size,
+                len: chunk_data.len(),
+            });
+            blobs.push(blob);
+        }
+        let blob_id = BlobId {
+            namespace: Some(namespace),
+            commitment: chunks[0].commitment,
+            height: 0,
+            tx_hash: String::new(),
+            chunks,
+        };
+        Ok((blobs, blob_id))
+    }
+
+    /// Rejects a payload exceeding [`DataAvailabilityClient::blob_size_limit()`] before any network requests are made.
+    fn check_payload_size(&self, data_len: usize) -> Result<(), DAError> {
+        match self.blob_size_limit() {
+            Some(limit) if data_len > limit => Err(to_non_retriable_da_error(anyhow::anyhow!(
+                "payload is too large: {data_len} bytes, while the limit is {limit} bytes"
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the maximum size of a single blob.
+    fn max_blob_size(&self) -> usize {
+        self.config.max_blob_size.unwrap_or(MAX_BLOB_SIZE)
+    }
+
+    /// Returns the maximum size of a payload. With chunking, all chunks of a payload are submitted in a single
+    /// transaction, so the payload is limited to the number of full chunks fitting into one.
+    fn max_payload_size(&self) -> usize {
+        let max_blob_size = self.max_blob_size();
+        if !self.config.allow_blob_chunking {
+            return max_blob_size;
+        }
+        // A blob of `MAX_BLOB_SIZE` bytes leaves room for the transaction envelope, so chunks get the same space.
+        let chunks_per_tx = blob_footprint(MAX_BLOB_SIZE) / blob_footprint(max_blob_size);
+        max_blob_size * chunks_per_tx.max(1)
+    }
+
+    /// Dispatches several blobs in as few Celestia transactions as fit the transaction size limit, so that
+    /// the transaction fee is paid once per transaction. Blobs are split into transactions with [`pack_blobs`].
+    ///
+    /// Returns a response per batch, in the same order as the input. Blobs submitted in the same transaction share
+    /// the same height. If any of the blobs exceeds the blob size limit, the whole call fails without submitting
+    /// anything. If submitting a transaction fails, the blobs of the previous transactions are already submitted;
+    /// they are recorded in the submission log, if it's configured.
+    pub async fn dispatch_blobs(
+        &self,
+        batches: Vec<(u32, Vec<u8>)>,
+    ) -> Result<Vec<DispatchResponse>, DAError> {
+        let size_limit = self.max_blob_size();
+        let namespace = self.namespaces[0];
+        let (batch_numbers, data): (Vec<_>, Vec<_>) = batches.into_iter().unzip();
+        for (batch_number, data) in batch_numbers.iter().zip(&data) {
+            if data.len() > size_limit {
+                return Err(to_non_retriable_da_error(anyhow::anyhow!(
+                    "blob for batch {batch_number} is too large: {} bytes, while the limit is {size_limit} bytes",
+                    data.len()
+                )));
+            }
+        }
+        let groups = pack_blobs(&data, MAX_TX_BYTES);
+        let mut blobs = data
+            .into_iter()
+            .map(|data| Blob::new(namespace, data).map_err(to_non_retriable_da_error))
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter();
+
+        let mut responses = Vec::with_capacity(batch_numbers.len());
+        for group in groups {
+            // Groups are consecutive runs of blobs in the input order.
+            let group_blobs: Vec<_> = blobs.by_ref().take(group.len()).collect();
+            let blob_ids = group_blobs
+                .iter()
+                .map(|blob| BlobId {
+                    namespace: Some(namespace),
+                    commitment: blob.commitment,
+                    height: 0,
+                    tx_hash: String::new(),
+                    chunks: vec![],
+                })
+                .collect();
+            let (_, group_responses) = self
+                .submit_and_log(namespace, group_blobs, blob_ids)
+                .await?;
+            responses.extend(group_responses);
+        }
+        Ok(responses)
+    }
+
+    /// Submits the blobs in a single transaction and returns the height at which it was included,
+    /// together with the hex-encoded transaction hash.
+    ///
+    /// Transient failures (including connection errors) are retried with an exponential backoff. If they persist,
+    /// a retriable error is returned, so that the caller can retry the whole dispatch later. Each retry bids
+    /// a higher gas price according to the configured escalation factor.
+    async fn submit_blobs(
+        &self,
+        namespace: Namespace,
+        blobs: Vec<Blob>,
+    ) -> Result<(u64, String), DAError> {
+        let attempt = AtomicU32::new(0);
+        let latency =
+            METRICS.submit_latency[&namespace_label(&self.namespaces, Some(namespace))].start();
+        let policy = RetryPolicy::submission(&self.config);
+        let result = retry_da(policy, "submit blobs", || async {
+            let attempt = attempt.fetch_add(1, Ordering::Relaxed);
+            self.submission_lock
+                .run(async {
+                    // `prepare` fetches the account on every attempt, so a resubmitted transaction
+                    // is always signed with the current sequence number.
+                    let blob_tx = self.client.prepare(blobs.clone(), attempt).await?;
+                    let blob_tx_hash = BlobTxHash::compute(&blob_tx);
+                    let tx_hash = blob_tx_hash.clone().hex();
+                    let height = self.client.submit(blob_tx_hash, blob_tx).await?;
+                    Ok((height, tx_hash))
+                })
+                .await
+                // Only transient errors are retried here; the final classification is done below.
+                .map_err(|err: CelestiaSdkError| DAError {
+                    is_retriable: err.is_transient(),
+                    error: err.into(),
+                })
+        })
+        .await
+        .map_err(|err| match err.error.downcast::<CelestiaSdkError>() {
+            Ok(sdk_err) => self.submit_error(sdk_err),
+            Err(error) => DAError {
+                error,
+                is_retriable: err.is_retriable,
+            },
+        });
+        if result.is_ok() {
+            latency.observe();
+        }
+        result
+    }
+
+    /// Returns the inclusion data of the blob, or `None` if it can't be reported as included yet.
+    async fn check_inclusion(&self, blob_id: &BlobId) -> Result<Option<InclusionData>, DAError> {
+        let head_height = retry_da(RetryPolicy::QUERY, "fetch latest height", || async {
+            self.client
+                .latest_height()
+                .await
+                .map_err(to_retriable_da_error)
+        })
+        .await?;
+        // The node we're connected to may lag behind the network, in which case it can't see the blob yet.
+        if head_height < blob_id.height {
+            tracing::debug!(head_height, "node hasn't reached the blob height yet");
+            return Ok(None);
+        }
+        // A shallow block can still be reorged, so the blob is reported as included only once it's deep enough.
+        let confirmation_depth = self.config.confirmation_depth;
+        if head_height < blob_id.height.saturating_add(confirmation_depth) {
+            return Ok(None);
+        }
         Ok(Some(InclusionData { data: vec![] }))
     }
 
+    /// Classifies a submission error that persisted after retries.
+    fn submit_error(&self, err: CelestiaSdkError) -> DAError {
+        if matches!(err, CelestiaSdkError::InsufficientFunds(_)) {
+            tracing::warn!(
+                "Celestia account {} has insufficient funds to pay for the blobs, \
+                 fund it so that the dispatch can be retried",
+                self.client.address()
+            );
+        }
+        classify_submit_error(err)
+    }
+}
+
+/// Maps a submission error to a `DAError`. Lack of funds is retriable, since the same blobs can be submitted
+/// once the account is topped up.
+fn classify_submit_error(err: CelestiaSdkError) -> DAError {
+    if err.is_transient() || matches!(err, CelestiaSdkError::InsufficientFunds(_)) {
+        to_retriable_da_error(err)
+    } else {
+        to_non_retriable_da_error(err)
+    }
+}
+
+/// Blob transaction combining blobs of several parties, prepared by [`CelestiaClient::prepare_combined()`].
+#[derive(Debug)]
+pub struct PreparedBlobTx {
+    blob_tx: BlobTx,
+    tx_hash: BlobTxHash,
+    /// ID of the client's own blob, lacking the height and the transaction hash.
+    blob_id: BlobId,
+}
+
+impl PreparedBlobTx {
+    /// Returns the hex-encoded hash of the transaction.
+    pub fn tx_hash(&self) -> String {
+        self.tx_hash.clone().hex()
+    }
+}
+
+/// Serializes blob submissions from the account. The sequence number fetched by `prepare` only advances
+/// once the previous transaction is committed, so concurrent submissions would otherwise sign transactions
+/// with the same sequence number and all but one of them would be rejected.
+#[derive(Debug, Default)]
+struct SubmissionLock(tokio::sync::Mutex<()>);
+
+impl SubmissionLock {
+    async fn run<T>(&self, submission: impl Future<Output = T>) -> T {
+        let _guard = self.0.lock().await;
+        submission.await
+    }
+}
+
+/// Remembers recently dispatched payloads, so that a dispatch retried after the original submission succeeded
+/// (e.g. because the response was lost) returns the original blob ID instead of paying for the blob again.
+///
+/// Payloads are keyed by the hash of the namespace and the data. Dispatches of the same payload running
+/// concurrently are not deduplicated.
+#[derive(Debug)]
+struct DispatchDedupCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<[u8; 32], (String, Instant)>>,
+}
+
+impl DispatchDedupCache {
+    fn from_config(config: &CelestiaConfig) -> Option<Arc<Self>> {
+        config.dispatch_dedup_ttl().map(|ttl| {
+            Arc::new(Self {
+                ttl,
+                entries: Mutex::default(),
+            })
+        })
+    }
+
+    fn key(namespace: &Namespace, data: &[u8]) -> [u8; 32] {
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(namespace.as_bytes());
+        hasher.update(data);
+        hasher.finalize().into()
+    }
+
+    fn get(&self, key: &[u8; 32]) -> Option<String> {
+        let entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        let (blob_id, dispatched_at) = entries.get(key)?;
+        (dispatched_at.elapsed() < self.ttl).then(|| blob_id.clone())
+    }
+
+    fn insert(&self, key: [u8; 32], blob_id: String) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+        entries.retain(|_, (_, dispatched_at)| dispatched_at.elapsed() < self.ttl);
+        if entries.len() >= MAX_DEDUPLICATED_DISPATCHES {
+            let oldest = entries
+                .iter()
+                .min_by_key(|(_, (_, dispatched_at))| *dispatched_at)
+                .map(|(key, _)| *key);
+            if let Some(oldest) = oldest {
+                entries.remove(&oldest);
+            }
+        }
+        entries.insert(key, (blob_id, Instant::now()));
+    }
+}
+
+/// Append-only log of successful submissions, one encoded blob ID per line.
+#[derive(Debug)]
+struct SubmissionLog {
+    path: PathBuf,
+    /// Serializes appends from clones of the client.
+    lock: Mutex<()>,
+}
+
+impl SubmissionLog {
+    fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            lock: Mutex::default(),
+        }
+    }
+
+    /// Appends the blob IDs of a submission with a single write.
+    async fn append(self: &Arc<Self>, blob_ids: &[&str]) -> anyhow::Result<()> {
+        let this = self.clone();
+        let line: String = blob_ids
+            .iter()
+            .map(|blob_id| format!("{blob_id}\n"))
+            .collect();
+        tokio::task::spawn_blocking(move || {
+            let _guard = this.lock.lock().unwrap_or_else(PoisonError::into_inner);
+            let mut file = fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&this.path)?;
+            file.write_all(line.as_bytes())?;
+            file.sync_data()
+        })
+        .await
+        .context("appending to the submission log panicked")?
+        .with_context(|| format!("failed to append to `{}`", self.path.display()))
+    }
+
+    fn read(&self) -> anyhow::Result<Vec<BlobId>> {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read `{}`", self.path.display()))
+            }
+        };
+        let blob_ids = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.is_empty())
+            .filter_map(|(i, line)| match line.parse::<BlobId>() {
+                Ok(blob_id) => Some(blob_id),
+                Err(err) => {
+                    tracing::warn!("skipping malformed submission log entry #{i}: {err:#}");
+                    None
+                }
+            })
+            .collect();
+        Ok(blob_ids)
+    }
+}
+
+/// Polling history of a blob that isn't included yet.
+#[derive(Debug)]
+struct InclusionAttempts {
+    first_polled_at: Instant,
+    count: u32,
+}
+
+/// Tracks for how long blobs have been polled for inclusion, so that a blob that never gets included
+/// eventually surfaces as failed instead of being polled indefinitely.
+#[derive(Debug, Default)]
+struct InclusionTracker {
+    attempts: Mutex<HashMap<String, InclusionAttempts>>,
+}
+
+impl InclusionTracker {
+    /// Records the result of an inclusion poll. If the blob is still pending after `max_wait` since it was
+    /// first polled, a non-retriable error is returned instead of the result.
+    ///
+    /// Blobs are only tracked while pending, i.e. while the poll returns `None` or a retriable error.
+    fn record(
+        &self,
+        blob_id: &str,
+        result: Result<Option<InclusionData>, DAError>,
+        max_wait: Option<Duration>,
+    ) -> Result<Option<InclusionData>, DAError> {
+        let Some(max_wait) = max_wait else {
+            return result;
+        };
+        let is_pending = match &result {
+            Ok(data) => data.is_none(),
+            Err(err) => err.is_retriable,
+        };
+        let mut attempts = self.attempts.lock().unwrap_or_else(PoisonError::into_inner);
+        if !is_pending {
+            attempts.remove(blob_id);
+            return result;
+        }
+
+        if !attempts.contains_key(blob_id) && attempts.len() >= MAX_TRACKED_INCLUSIONS {
+            // Forget the oldest blob; it'll be tracked anew if it's polled again.
+            let oldest = attempts
+                .iter()
+                .min_by_key(|(_, blob_attempts)| blob_attempts.first_polled_at)
+                .map(|(blob_id, _)| blob_id.clone());
+            if let Some(oldest) = oldest {
+                attempts.remove(&oldest);
+            }
+        }
+        let blob_attempts =
+            attempts
+                .entry(blob_id.to_owned())
+                .or_insert_with(|| InclusionAttempts {
+                    first_polled_at: Instant::now(),
+                    count: 0,
+                });
+        blob_attempts.count += 1;
+        let elapsed = blob_attempts.first_polled_at.elapsed();
+        if elapsed < max_wait {
+            return result;
+        }
+
+        let count = blob_attempts.count;
+        attempts.remove(blob_id);
+        let message = format!("blob {blob_id} was not included after {count} polls in {elapsed:?}");
+        let error = match result {
+            Err(err) => err.error.context(message),
+            Ok(_) => anyhow::anyhow!(message),
+        };
+        Err(to_non_retriable_da_error(error))
+    }
+}
+
+/// Periodically checks the account balance and warns once it drops below `threshold`.
+///
+/// Only holds a weak reference to the node client, so it stops once all clients using it are dropped.
+async fn monitor_balance(
+    node_client: Weak<CelestiaNodeClient>,
+    address: String,
+    threshold: u64,
+    poll_interval: Duration,
+) {
+    let mut interval = tokio::time::interval(poll_interval);
+    loop {
+        interval.tick().await;
+        let Some(node_client) = node_client.upgrade() else {
+            tracing::debug!("Celestia client was dropped, stopping balance monitor");
+            return;
+        };
+
+        match node_client.balance_for_address(&address).await {
+            Ok(balance) => {
+                METRICS.balance.set(balance);
+                if balance < threshold {
+                    tracing::warn!(
+                        balance,
+                        threshold,
+                        "Celestia account {address} balance is below the warning threshold"
+                    );
+                }
+            }
+            Err(err) => tracing::warn!(%err, "failed to fetch Celestia account balance"),
+        }
+    }
+}
+
+/// Builds the TLS configuration for the gRPC endpoint, or returns `None` if no TLS files are configured,
+/// in which case tonic's defaults apply. The files are read eagerly so that a misconfiguration is reported on startup.
+fn tls_config(config: &CelestiaConfig) -> anyhow::Result<Option<ClientTlsConfig>> {
+    fn read_pem(path: &str, kind: &str) -> anyhow::Result<Vec<u8>> {
+        fs::read(path).with_context(|| format!("failed to read TLS {kind} from `{path}`"))
+    }
+
+    let identity = match (&config.tls_client_cert_path, &config.tls_client_key_path) {
+        (Some(cert_path), Some(key_path)) => Some(Identity::from_pem(
+            read_pem(cert_path, "client certificate")?,
+            read_pem(key_path, "client key")?,
+        )),
+        (None, None) => None,
+        _ => anyhow::bail!("tls_client_cert_path and tls_client_key_path must be set together"),
+    };
+    let ca_cert = config
+        .tls_ca_cert_path
+        .as_deref()
+        .map(|path| read_pem(path, "CA certificate"))
+        .transpose()?
+        .map(Certificate::from_pem);
+
+    if identity.is_none() && ca_cert.is_none() {
+        return Ok(None);
+    }
+    let mut tls_config = ClientTlsConfig::new();
+    if let Some(ca_cert) = ca_cert {
+        tls_config = tls_config.ca_certificate(ca_cert);
+    }
+    if let Some(identity) = identity {
+        tls_config = tls_config.identity(identity);
+    }
+    Ok(Some(tls_config))
+}
+
+/// Size of a version 0 namespace ID, not counting the leading zero bytes that are reserved by the protocol.
+const NAMESPACE_V0_ID_SIZE: usize = 10;
+
+fn parse_namespace(namespace: &str) -> anyhow::Result<Namespace> {
+    let namespace_bytes = hex::decode(namespace).context("namespace is not valid hex")?;
+    // Leading zeros are allowed, since they are a part of the full namespace encoding.
+    let significant_len = namespace_bytes
+        .iter()
+        .position(|&byte| byte != 0)
+        .map_or(0, |pos| namespace_bytes.len() - pos);
+    anyhow::ensure!(
+        significant_len <= NAMESPACE_V0_ID_SIZE,
+        "namespace must be at most {NAMESPACE_V0_ID_SIZE} bytes for version 0, got {significant_len} bytes"
+    );
+    Ok(Namespace::new_v0(namespace_bytes.as_slice())?)
+}
+
+/// Parses the configured namespaces, rejecting duplicates: a namespace listed twice, possibly with a different
+/// number of leading zeros, would make the routing by namespace index ambiguous.
+fn parse_namespaces(namespaces: &[String]) -> anyhow::Result<Vec<Namespace>> {
+    let mut parsed = Vec::with_capacity(namespaces.len());
+    for (idx, namespace) in namespaces.iter().enumerate() {
+        let parsed_namespace = parse_namespace(namespace)
+            .with_context(|| format!("invalid Celestia namespace `{namespace}`"))?;
+        if let Some(prev_idx) = parsed.iter().position(|&prev| prev == parsed_namespace) {
+            anyhow::bail!(
+                "Celestia namespace `{namespace}` at index {idx} duplicates namespace `{}` at index {prev_idx}",
+                namespaces[prev_idx]
+            );
+        }
+        parsed.push(parsed_namespace);
+    }
+    Ok(parsed)
+}
+
+/// Identifier of a dispatched blob.
+///
+/// Encoded as `v1:<namespace_hex>:<height>:<commitment_hex>:<tx_hash>`, followed by `:<chunks>` if the payload
+/// was split into several blobs. The namespace is empty for blob IDs decoded from the legacy bincode encoding,
+/// which didn't include it.
+#[derive(Debug, PartialEq)]
+pub struct BlobId {
+    pub namespace: Option<Namespace>,
+    /// Commitment of the blob, or of the first chunk if the payload was split.
+    pub commitment: Commitment,
+    pub height: u64,
+    /// Hex-encoded hash of the transaction that included the blob, can be looked up in a block explorer.
+    /// Empty for blob IDs created before the hash was recorded.
+    pub tx_hash: String,
+    /// Chunks of the payload in order, empty if the payload was submitted as a single blob.
+    pub chunks: Vec<BlobChunk>,
+}
+
+/// A part of a payload that was split into several blobs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlobChunk {
+    pub commitment: Commitment,
+    /// Offset of the chunk in the payload.
+    pub offset: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for BlobChunk {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}",
+            hex::encode(self.commitment.0),
+            self.offset,
+            self.len
+        )
+    }
+}
+
+impl FromStr for BlobChunk {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<_> = s.split('/').collect();
+        let [commitment, offset, len] = fields.as_slice() else {
+            anyhow::bail!("expected 3 fields in blob chunk, got {}", fields.len());
+        };
+        Ok(Self {
+            commitment: parse_commitment(commitment)?,
+            offset: offset.parse().context("invalid offset")?,
+            len: len.parse().context("invalid length")?,
+        })
+    }
+}
+
+impl BlobId {
+    const VERSION_PREFIX: &'static str = "v1:";
+
+    /// Returns a short prefix of the commitment, enough to tell blobs apart in logs.
+    fn short_id(&self) -> String {
+        hex::encode(&self.commitment.0[..4])
+    }
+
+    fn to_dispatch_response(&self) -> DispatchResponse {
+        DispatchResponse {
+            blob_id: self.to_string(),
+        }
+    }
+
+    /// Decodes a blob ID in the legacy hex-encoded bincode format, with or without the transaction hash.
+    fn decode_legacy(blob_id: &str) -> anyhow::Result<Self> {
+        #[derive(Deserialize)]
+        struct LegacyBlobId {
+            commitment: Commitment,
+            height: u64,
+            tx_hash: String,
+        }
+
+        #[derive(Deserialize)]
+        struct LegacyBlobIdWithoutTxHash {
+            commitment: Commitment,
+            height: u64,
+        }
+
+        let blob_bytes = hex::decode(blob_id)?;
+        let LegacyBlobId {
+            commitment,
+            height,
+            tx_hash,
+        } = match bincode::deserialize(&blob_bytes) {
+            Ok(blob_id) => blob_id,
+            Err(_) => {
+                let LegacyBlobIdWithoutTxHash { commitment, height } =
+                    bincode::deserialize(&blob_bytes)?;
+                LegacyBlobId {
+                    commitment,
+                    height,
+                    tx_hash: String::new(),
+                }
+            }
+        };
+        Ok(Self {
+            namespace: None,
+            commitment,
+            height,
+            tx_hash,
+            chunks: vec![],
+        })
+    }
+}
+
+impl fmt::Display for BlobId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let namespace = self
+            .namespace
+            .map(|namespace| hex::encode(namespace.as_bytes()))
+            .unwrap_or_default();
+        write!(
+            f,
+            "{}{namespace}:{}:{}:{}",
+            Self::VERSION_PREFIX,
+            self.height,
+            hex::encode(self.commitment.0),
+            self.tx_hash
+        )?;
+        if !self.chunks.is_empty() {
+            let chunks: Vec<_> = self.chunks.iter().map(BlobChunk::to_string).collect();
+            write!(f, ":{}", chunks.join(","))?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for BlobId {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some(fields) = s.strip_prefix(Self::VERSION_PREFIX) else {
+            return Self::decode_legacy(s).context("failed to decode legacy blob ID");
+        };
+
+        let fields: Vec<_> = fields.split(':').collect();
+        let (namespace, height, commitment, tx_hash, chunks) = match fields.as_slice() {
+            [namespace, height, commitment, tx_hash] => {
+                (namespace, height, commitment, tx_hash, vec![])
+            }
+            [namespace, height, commitment, tx_hash, chunks] => {
+                let chunks = chunks
+                    .split(',')
+                    .map(BlobChunk::from_str)
+                    .collect::<anyhow::Result<Vec<_>>>()
+                    .context("invalid chunks")?;
+                (namespace, height, commitment, tx_hash, chunks)
+            }
+            _ => anyhow::bail!("expected 4 or 5 fields in blob ID, got {}", fields.len()),
+        };
+
+        let mut expected_offset = 0_usize;
+        for chunk in &chunks {
+            anyhow::ensure!(
+                chunk.offset == expected_offset,
+                "chunk offset {} doesn't follow the previous chunk, {expected_offset} expected",
+                chunk.offset
+            );
+            expected_offset = expected_offset
+                .checked_add(chunk.len)
+                .context("chunk lengths overflow")?;
+        }
+
+        let namespace = if namespace.is_empty() {
+            None
+        } else {
+            let namespace_bytes = hex::decode(namespace).context("invalid namespace")?;
+            Some(Namespace::from_raw(&namespace_bytes).context("invalid namespace")?)
+        };
+        let height = height.parse().context("invalid height")?;
+
+        Ok(Self {
+            namespace,
+            commitment: parse_commitment(commitment)?,
+            height,
+            tx_hash: tx_hash.to_string(),
+            chunks,
+        })
+    }
+}
+
+fn parse_commitment(commitment: &str) -> anyhow::Result<Commitment> {
+    let commitment = hex::decode(commitment).context("invalid commitment")?;
+    let commitment = <[u8; 32]>::try_from(commitment)
+        .map_err(|bytes| anyhow::anyhow!("invalid commitment length: {}", bytes.len()))?;
+    Ok(Commitment(commitment))
+}
+
+#[async_trait]
+impl DataAvailabilityClient for CelestiaClient {
+    #[tracing::instrument(skip(self, data))]
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        self.dispatch_blob_to_namespace(0, data)
+            .await
+            .inspect_err(|err| {
+                let namespace = namespace_label(&self.namespaces, self.namespaces.first().copied());
+                METRICS.observe_error(err, Some(namespace));
+            })
+    }
+
+    #[tracing::instrument(
+        skip_all,
+        fields(height = tracing::field::Empty, blob_id = tracing::field::Empty)
+    )]
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let parsed_blob_id = blob_id
+            .parse::<BlobId>()
+            .map_err(to_non_retriable_da_error)
+            .inspect_err(|err| METRICS.observe_error(err, None))?;
+        let namespace = namespace_label(&self.namespaces, parsed_blob_id.namespace);
+        let latency = METRICS.inclusion_poll_latency[&namespace].start();
+        let span = tracing::Span::current();
+        span.record("height", parsed_blob_id.height);
+        span.record("blob_id", parsed_blob_id.short_id().as_str());
+
+        let result = self.check_inclusion(&parsed_blob_id).await;
+        let result =
+            self.inclusion_tracker
+                .record(blob_id, result, self.config.max_inclusion_wait());
+        match &result {
+            Ok(Some(_)) => {
+                latency.observe();
+                tracing::info!("inclusion complete");
+            }
+            Ok(None) => tracing::debug!("inclusion pending"),
+            Err(err) if err.is_retriable => {
+                tracing::debug!(err = %err.error, "inclusion pending after a retriable error");
+            }
+            Err(err) => tracing::warn!(err = %err.error, "inclusion failed"),
+        }
+        result.inspect_err(|err| METRICS.observe_error(err, Some(namespace)))
+    }
+
+    async fn get_inclusion_data_batch(
+        &self,
+        blob_ids: &[String],
+    ) -> Result<Vec<Option<InclusionData>>, DAError> {
+        self.poll_many(blob_ids).await.into_iter().collect()
+    }
+
+    async fn estimate_dispatch_cost(&self, data_len: usize) -> Result<u64, DAError> {
+        // Mirrors the blobs produced by `build_blobs()`.
+        let max_blob_size = self.max_blob_size();
+        let blob_sizes: Vec<usize> =
+            if !self.config.allow_blob_chunking || data_len <= max_blob_size {
+                vec![data_len]
+            } else {
+                let full_chunks = data_len / max_blob_size;
+                let mut sizes = vec![max_blob_size; full_chunks];
+                if data_len % max_blob_size != 0 {
+                    sizes.push(data_len % max_blob_size);
+                }
+                sizes
+            };
+        let blob_sizes = blob_sizes
+            .into_iter()
+            .map(u32::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .context("blob is too large")
+            .map_err(to_non_retriable_da_error)?;
+
+        self.client
+            .estimate_fee(&blob_sizes)
+            .await
+            .map_err(to_retriable_da_error)
+            .inspect_err(|err| METRICS.observe_error(err, None))
+    }
+
     fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
         Box::new(self.clone())
     }
 
     fn blob_size_limit(&self) -> Option<usize> {
-        Some(1973786) // almost 2MB
+        Some(self.max_payload_size())
     }
 }
 
@@ -103,7 +1508,559 @@ impl Debug for CelestiaClient {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("CelestiaClient")
             .field("config.api_node_url", &self.config.api_node_url)
-            .field("config.namespace", &self.config.namespace)
+            .field("config.namespaces", &self.config.namespaces())
             .finish()
     }
 }
+
+/// Greedily packs blobs into transactions, returning the indices of the blobs in each transaction.
+///
+/// Blobs are packed in their original order, so each transaction gets a consecutive run of blobs
+/// and the result is deterministic for the given inputs.
+/// Each blob is accounted for by the number of shares it occupies plus the per-blob info that is
+/// included into `MsgPayForBlobs`. A blob that doesn't fit into the target on its own is placed
+/// into a separate transaction.
+pub fn pack_blobs(data: &[Vec<u8>], max_tx_bytes: usize) -> Vec<Vec<usize>> {
+    let target_bytes = max_tx_bytes / 100 * TARGET_TX_UTILIZATION_PERCENT;
+
+    let mut groups = vec![];
+    let mut current_group = vec![];
+    let mut current_bytes = 0_usize;
+    for (idx, blob) in data.iter().enumerate() {
+        let footprint = blob_footprint(blob.len());
+        if !current_group.is_empty() && current_bytes.saturating_add(footprint) > target_bytes {
+            groups.push(std::mem::take(&mut current_group));
+            current_bytes = 0;
+        }
+        current_group.push(idx);
+        current_bytes = current_bytes.saturating_add(footprint);
+    }
+    if !current_group.is_empty() {
+        groups.push(current_group);
+    }
+
+    groups
+}
+
+/// Returns the number of bytes a blob of the given size occupies in a transaction.
+fn blob_footprint(blob_len: usize) -> usize {
+    let shares = sparse_shares_needed(u32::try_from(blob_len).unwrap_or(u32::MAX));
+    let bytes = shares
+        .saturating_mul(SHARE_SIZE)
+        .saturating_add(BYTES_PER_BLOB_INFO);
+    usize::try_from(bytes).unwrap_or(usize::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use zksync_config::configs::da_client::celestia::CelestiaPrivateKey;
+
+    use super::*;
+
+    /// Creates a client that doesn't connect anywhere until a request is made.
+    async fn test_client(config: CelestiaConfig) -> CelestiaClient {
+        let secrets = CelestiaSecrets {
+            private_key: CelestiaPrivateKey::Inline("11".repeat(32).parse().unwrap()),
+            node_auth_token: None,
+        };
+        let config = CelestiaConfig {
+            api_node_url: "http://127.0.0.1:1".to_owned(),
+            namespace: "deadbeef".to_owned(),
+            chain_id: "test".to_owned(),
+            timeout_ms: 1_000,
+            ..config
+        };
+        CelestiaClient::new(config, secrets).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn clones_share_caches() {
+        let client = test_client(CelestiaConfig {
+            dispatch_dedup_ttl_ms: Some(3_600_000),
+            ..CelestiaConfig::default()
+        })
+        .await;
+        let boxed = client.clone_boxed();
+        let cloned = client.clone();
+
+        // Simulate a dispatch on the original client; the clone must reuse its blob ID without submitting anything.
+        let key = DispatchDedupCache::key(&client.namespaces[0], b"data");
+        client
+            .dispatch_dedup
+            .as_ref()
+            .unwrap()
+            .insert(key, "cached".to_owned());
+        let response = boxed.dispatch_blob(1, b"data".to_vec()).await.unwrap();
+        assert_eq!(response.blob_id, "cached");
+
+        *client.cached_balance.lock().unwrap() = Some((42, Instant::now()));
+        assert_eq!(cloned.balance().await.unwrap(), 42);
+
+        let result =
+            client
+                .inclusion_tracker
+                .record("a", Ok(None), Some(Duration::from_secs(3600)));
+        assert!(matches!(result, Ok(None)));
+        assert!(cloned
+            .inclusion_tracker
+            .attempts
+            .lock()
+            .unwrap()
+            .contains_key("a"));
+    }
+
+    #[tokio::test]
+    async fn oversized_payload_is_rejected_upfront() {
+        let client = test_client(CelestiaConfig {
+            max_blob_size: Some(16),
+            ..CelestiaConfig::default()
+        })
+        .await;
+        let started_at = Instant::now();
+        let Err(err) = client.dispatch_blob(1, vec![0; 17]).await else {
+            panic!("oversized payload was dispatched");
+        };
+        assert!(started_at.elapsed() < Duration::from_millis(100));
+        assert!(!err.is_retriable);
+        assert_eq!(
+            err.error.to_string(),
+            "payload is too large: 17 bytes, while the limit is 16 bytes"
+        );
+    }
+
+    #[tokio::test]
+    async fn chunked_payloads_are_limited_by_tx_size() {
+        let client = test_client(CelestiaConfig {
+            max_blob_size: Some(1_000),
+            allow_blob_chunking: true,
+            ..CelestiaConfig::default()
+        })
+        .await;
+        let limit = client.blob_size_limit().unwrap();
+        assert_eq!(limit, 1_305_000);
+        let (blobs, _) = client
+            .build_blobs(client.namespaces[0], vec![0; limit])
+            .unwrap();
+        let footprint: usize = blobs
+            .iter()
+            .map(|blob| blob_footprint(blob.data.len()))
+            .sum();
+        assert!(footprint <= MAX_TX_BYTES);
+
+        let Err(err) = client.dispatch_blob(1, vec![0; MAX_TX_BYTES + 1]).await else {
+            panic!("oversized payload was dispatched");
+        };
+        assert!(!err.is_retriable);
+        assert_eq!(
+            err.error.to_string(),
+            format!("payload is too large: 2097153 bytes, while the limit is {limit} bytes")
+        );
+    }
+
+    #[test]
+    fn inclusion_tracker_gives_up_after_max_wait() {
+        let tracker = InclusionTracker::default();
+        let pending = || Ok(None);
+
+        let result = tracker.record("a", pending(), Some(Duration::from_secs(3600)));
+        assert!(matches!(result, Ok(None)));
+        let result = tracker.record(
+            "a",
+            Ok(Some(InclusionData { data: vec![] })),
+            Some(Duration::from_secs(3600)),
+        );
+        assert!(matches!(result, Ok(Some(_))));
+        assert!(tracker.attempts.lock().unwrap().is_empty());
+
+        let Err(err) = tracker.record("b", pending(), Some(Duration::ZERO)) else {
+            panic!("expected an error after max wait");
+        };
+        assert!(!err.is_retriable);
+        assert!(tracker.attempts.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn recent_dispatches_are_deduplicated() {
+        let config = CelestiaConfig {
+            dispatch_dedup_ttl_ms: Some(3_600_000),
+            ..CelestiaConfig::default()
+        };
+        let dedup = DispatchDedupCache::from_config(&config).unwrap();
+        let namespace = parse_namespace("deadbeef").unwrap();
+        let other_namespace = parse_namespace("beefdead").unwrap();
+
+        let key = DispatchDedupCache::key(&namespace, b"data");
+        assert_eq!(dedup.get(&key), None);
+        dedup.insert(key, "blob".to_owned());
+        assert_eq!(
+            dedup.get(&DispatchDedupCache::key(&namespace, b"data")),
+            Some("blob".to_owned())
+        );
+        assert_eq!(
+            dedup.get(&DispatchDedupCache::key(&namespace, b"other data")),
+            None
+        );
+        assert_eq!(
+            dedup.get(&DispatchDedupCache::key(&other_namespace, b"data")),
+            None
+        );
+
+        let expired = DispatchDedupCache::from_config(&CelestiaConfig {
+            dispatch_dedup_ttl_ms: Some(0),
+            ..config
+        })
+        .unwrap();
+        expired.insert(key, "blob".to_owned());
+        assert_eq!(expired.get(&key), None);
+        assert!(DispatchDedupCache::from_config(&CelestiaConfig::default()).is_none());
+    }
+
+    #[tokio::test]
+    async fn submissions_are_recovered_from_log() {
+        let path = std::env::temp_dir().join(format!(
+            "celestia-submission-log-{}.txt",
+            std::process::id()
+        ));
+        let log = Arc::new(SubmissionLog::new(path.clone()));
+        assert!(log.read().unwrap().is_empty());
+
+        let blob_ids: Vec<_> = (1..=2)
+            .map(|height| BlobId {
+                namespace: Some(parse_namespace("deadbeef").unwrap()),
+                commitment: Commitment([1; 32]),
+                height,
+                tx_hash: "ab".repeat(32),
+                chunks: vec![],
+            })
+            .collect();
+        for blob_id in &blob_ids {
+            log.append(&[&blob_id.to_dispatch_response().blob_id])
+                .await
+                .unwrap();
+        }
+        // Simulate a write torn by a crash.
+        log.append(&["v1:dead"]).await.unwrap();
+
+        let recovered = log.read().unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(recovered, blob_ids);
+    }
+
+    #[tokio::test]
+    async fn batched_submissions_are_logged() {
+        let path = std::env::temp_dir().join(format!(
+            "celestia-batched-submission-log-{}.txt",
+            std::process::id()
+        ));
+        let client = test_client(CelestiaConfig {
+            submission_log_path: Some(path.to_str().unwrap().to_owned()),
+            ..CelestiaConfig::default()
+        })
+        .await;
+
+        let blob_id = |commitment, height, tx_hash: &str| BlobId {
+            namespace: Some(client.namespaces[0]),
+            commitment: Commitment([commitment; 32]),
+            height,
+            tx_hash: tx_hash.to_owned(),
+            chunks: vec![],
+        };
+        let tx_hash = "ab".repeat(32);
+        // Blob IDs as prepared by `dispatch_blobs()`, before the transaction is submitted.
+        let prepared = vec![blob_id(1, 0, ""), blob_id(2, 0, "")];
+        let responses = client.record_submission(prepared, 7, tx_hash.clone()).await;
+
+        let recovered = client.recover_pending().await.unwrap();
+        fs::remove_file(&path).unwrap();
+        let expected = vec![blob_id(1, 7, &tx_hash), blob_id(2, 7, &tx_hash)];
+        assert_eq!(recovered, expected);
+        let responses: Vec<_> = responses
+            .into_iter()
+            .map(|response| response.blob_id)
+            .collect();
+        let expected_responses: Vec<_> = expected
+            .iter()
+            .map(|blob_id| blob_id.to_dispatch_response().blob_id)
+            .collect();
+        assert_eq!(responses, expected_responses);
+    }
+
+    #[tokio::test]
+    async fn concurrent_submissions_get_distinct_sequences() {
+        // Mimics the account: the sequence is read when preparing a transaction and advances once it's committed.
+        let lock = SubmissionLock::default();
+        let committed_sequence = std::sync::atomic::AtomicU64::new(0);
+        let submit = || {
+            lock.run(async {
+                let sequence = committed_sequence.load(Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                committed_sequence.store(sequence + 1, Ordering::SeqCst);
+                sequence
+            })
+        };
+
+        let mut sequences = futures::future::join_all((0..5).map(|_| submit())).await;
+        sequences.sort_unstable();
+        assert_eq!(sequences, [0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn submit_errors_are_classified() {
+        let retriable = [
+            CelestiaSdkError::NonceConflict("expected 5, got 4".to_owned()),
+            CelestiaSdkError::InsufficientFunds(
+                "spendable balance 10utia is smaller than 2000utia".to_owned(),
+            ),
+            CelestiaSdkError::TxRejected {
+                code: 20,
+                codespace: "sdk".to_owned(),
+                log: "mempool is full".to_owned(),
+            },
+        ];
+        for err in retriable {
+            let message = err.to_string();
+            assert!(classify_submit_error(err).is_retriable, "{message}");
+        }
+
+        let permanent = [
+            CelestiaSdkError::TxRejected {
+                code: 13,
+                codespace: "sdk".to_owned(),
+                log: "insufficient fee; got: 100utia required: 200utia".to_owned(),
+            },
+            CelestiaSdkError::BlobTooLarge("size: 3000000".to_owned()),
+            CelestiaSdkError::Other(anyhow::anyhow!("invalid namespace")),
+        ];
+        for err in permanent {
+            let message = err.to_string();
+            assert!(!classify_submit_error(err).is_retriable, "{message}");
+        }
+
+        // The classified error stays available to the callers.
+        let error = classify_submit_error(CelestiaSdkError::NonceConflict(String::new())).error;
+        assert!(matches!(
+            error.downcast_ref::<CelestiaSdkError>(),
+            Some(CelestiaSdkError::NonceConflict(_))
+        ));
+    }
+
+    #[test]
+    fn max_blob_fits_into_transaction() {
+        assert!(blob_footprint(MAX_BLOB_SIZE) <= MAX_TX_BYTES);
+        // The rest of the transaction (signature, fee, etc.) takes a few hundred bytes.
+        assert!(MAX_TX_BYTES - blob_footprint(MAX_BLOB_SIZE) >= 400);
+    }
+
+    #[test]
+    fn pack_blobs_respects_tx_limit() {
+        // Each 1000-byte blob occupies 3 shares (1536 bytes) plus 70 bytes of blob info.
+        let data = vec![vec![0; 1000]; 5];
+        let groups = pack_blobs(&data, 5_000);
+        assert_eq!(groups, vec![vec![0, 1], vec![2, 3], vec![4]]);
+    }
+
+    #[test]
+    fn pack_blobs_isolates_oversized_blobs() {
+        let data = vec![vec![0; 100], vec![0; 10_000], vec![0; 100]];
+        let groups = pack_blobs(&data, 5_000);
+        assert_eq!(groups, vec![vec![0], vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn pack_blobs_handles_empty_input() {
+        assert!(pack_blobs(&[], 5_000).is_empty());
+    }
+
+    #[test]
+    fn malformed_namespaces_are_reported() {
+        parse_namespace("deadbeef").unwrap();
+        // Leading zeros of the full 29-byte encoding don't count towards the ID size.
+        parse_namespace(&format!("{}{}", "00".repeat(19), "ab".repeat(10))).unwrap();
+
+        for namespace in ["not hex", "abc", "0x00ab"] {
+            let err = parse_namespace(namespace).unwrap_err().to_string();
+            assert_eq!(err, "namespace is not valid hex", "{namespace}");
+        }
+
+        let err = parse_namespace(&"ab".repeat(11)).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "namespace must be at most 10 bytes for version 0, got 11 bytes"
+        );
+    }
+
+    #[test]
+    fn duplicate_namespaces_are_rejected() {
+        let namespaces = ["deadbeef", "cafe"].map(str::to_owned);
+        assert_eq!(parse_namespaces(&namespaces).unwrap().len(), 2);
+
+        let namespaces = ["deadbeef", "cafe", "deadbeef"].map(str::to_owned);
+        let err = parse_namespaces(&namespaces).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Celestia namespace `deadbeef` at index 2 duplicates namespace `deadbeef` at index 0"
+        );
+
+        // Leading zeros don't change the decoded namespace.
+        let namespaces = ["deadbeef", "0000deadbeef"].map(str::to_owned);
+        let err = parse_namespaces(&namespaces).unwrap_err().to_string();
+        assert_eq!(
+            err,
+            "Celestia namespace `0000deadbeef` at index 1 duplicates namespace `deadbeef` at index 0"
+        );
+    }
+
+    #[test]
+    fn namespace_labels_are_bounded() {
+        let configured = parse_namespace("deadbeef").unwrap();
+        let other = parse_namespace("cafe").unwrap();
+        assert_eq!(
+            namespace_label(&[configured], Some(configured)),
+            hex::encode(configured.as_bytes())
+        );
+        assert_eq!(namespace_label(&[configured], Some(other)), "other");
+        assert_eq!(namespace_label(&[configured], None), "other");
+    }
+
+    #[test]
+    fn blob_id_roundtrip() {
+        let blob_id = BlobId {
+            namespace: Some(parse_namespace("deadbeef").unwrap()),
+            commitment: Commitment([1; 32]),
+            height: 42,
+            tx_hash: "ab".repeat(32),
+            chunks: vec![],
+        };
+        let encoded = blob_id.to_dispatch_response().blob_id;
+        assert!(encoded.starts_with("v1:"));
+        assert_eq!(encoded.parse::<BlobId>().unwrap(), blob_id);
+    }
+
+    fn arbitrary_namespace() -> impl Strategy<Value = Namespace> {
+        proptest::collection::vec(any::<u8>(), 0..=NAMESPACE_V0_ID_SIZE)
+            .prop_map(|id| Namespace::new_v0(&id).unwrap())
+    }
+
+    /// Generates blob IDs the way `dispatch_blob_to_namespace()` produces them, plus IDs without a namespace
+    /// as re-encoded from the legacy format.
+    fn arbitrary_blob_id() -> impl Strategy<Value = BlobId> {
+        let tx_hash = proptest::option::of(any::<[u8; 32]>())
+            .prop_map(|hash| hash.map(hex::encode).unwrap_or_default());
+        let chunk_lens = proptest::collection::vec((any::<[u8; 32]>(), 1..=MAX_BLOB_SIZE), 0..4);
+        (
+            proptest::option::of(arbitrary_namespace()),
+            any::<[u8; 32]>(),
+            any::<u64>(),
+            tx_hash,
+            chunk_lens,
+        )
+            .prop_map(|(namespace, commitment, height, tx_hash, chunk_lens)| {
+                let mut offset = 0;
+                let chunks = chunk_lens
+                    .into_iter()
+                    .map(|(commitment, len)| {
+                        let chunk = BlobChunk {
+                            commitment: Commitment(commitment),
+                            offset,
+                            len,
+                        };
+                        offset += len;
+                        chunk
+                    })
+                    .collect();
+                BlobId {
+                    namespace,
+                    commitment: Commitment(commitment),
+                    height,
+                    tx_hash,
+                    chunks,
+                }
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn arbitrary_blob_ids_roundtrip(blob_id in arbitrary_blob_id()) {
+            let encoded = blob_id.to_dispatch_response().blob_id;
+            prop_assert_eq!(encoded.parse::<BlobId>().unwrap(), blob_id);
+        }
+
+        #[test]
+        fn truncated_blob_ids_are_handled(blob_id in arbitrary_blob_id(), cut in any::<prop::sample::Index>()) {
+            let encoded = blob_id.to_dispatch_response().blob_id;
+            let truncated = &encoded[..cut.index(encoded.len())];
+            // Some prefixes are valid blob IDs themselves (e.g. with a shorter height); others must be rejected
+            // with an error rather than a panic.
+            if let Ok(decoded) = truncated.parse::<BlobId>() {
+                prop_assert_eq!(decoded.to_string(), truncated);
+            }
+        }
+
+        #[test]
+        fn malformed_blob_ids_are_rejected_without_panics(blob_id in "(v1:)?[0-9a-f:/,]{0,200}") {
+            let _ = blob_id.parse::<BlobId>();
+        }
+    }
+
+    #[test]
+    fn chunk_length_overflow_is_rejected() {
+        let commitment = hex::encode([1; 32]);
+        let blob_id = format!(
+            "v1::1:{commitment}::{commitment}/0/{max},{commitment}/{max}/1",
+            max = usize::MAX
+        );
+        let err = blob_id.parse::<BlobId>().unwrap_err();
+        assert_eq!(err.to_string(), "chunk lengths overflow");
+    }
+
+    #[test]
+    fn chunked_blob_id_roundtrip() {
+        let chunks = vec![
+            BlobChunk {
+                commitment: Commitment([1; 32]),
+                offset: 0,
+                len: 100,
+            },
+            BlobChunk {
+                commitment: Commitment([2; 32]),
+                offset: 100,
+                len: 50,
+            },
+        ];
+        let blob_id = BlobId {
+            namespace: Some(parse_namespace("deadbeef").unwrap()),
+            commitment: chunks[0].commitment,
+            height: 42,
+            tx_hash: "ab".repeat(32),
+            chunks,
+        };
+        let encoded = blob_id.to_dispatch_response().blob_id;
+        assert_eq!(encoded.parse::<BlobId>().unwrap(), blob_id);
+
+        let gapped = encoded.replace("/100/50", "/101/50");
+        assert!(gapped.parse::<BlobId>().is_err());
+    }
+
+    #[test]
+    fn legacy_blob_ids_are_decoded() {
+        let legacy_bytes = bincode::serialize(&(Commitment([1; 32]), 42_u64)).unwrap();
+        let blob_id: BlobId = hex::encode(legacy_bytes).parse().unwrap();
+        assert_eq!(blob_id.namespace, None);
+        assert_eq!(blob_id.commitment, Commitment([1; 32]));
+        assert_eq!(blob_id.height, 42);
+        assert!(blob_id.tx_hash.is_empty());
+
+        let legacy_bytes = bincode::serialize(&(Commitment([1; 32]), 42_u64, "ab")).unwrap();
+        let blob_id: BlobId = hex::encode(legacy_bytes).parse().unwrap();
+        assert_eq!(blob_id.height, 42);
+        assert_eq!(blob_id.tx_hash, "ab");
+    }
+
+    #[test]
+    fn malformed_blob_ids_are_rejected() {
+        assert!("v1:00:42".parse::<BlobId>().is_err());
+        assert!("v1::42:0102:".parse::<BlobId>().is_err());
+        assert!("not hex".parse::<BlobId>().is_err());
+    }
+}
@@ -0,0 +1,190 @@
+// This file is @generated by prost-build.
+// Trimmed down to the fields used by the client; unknown fields are skipped when decoding.
+/// GetLatestBlockRequest is the request type for the Query/GetLatestBlock RPC method.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct GetLatestBlockRequest {}
+impl ::prost::Name for GetLatestBlockRequest {
+    const NAME: &'static str = "GetLatestBlockRequest";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetLatestBlockRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetLatestBlockRequest".into()
+    }
+}
+/// GetLatestBlockResponse is the response type for the Query/GetLatestBlock RPC method.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetLatestBlockResponse {
+    /// Deprecated: please use `sdk_block` instead. The header layout is compatible with `Block`.
+    #[prost(message, optional, tag = "2")]
+    pub block: ::core::option::Option<Block>,
+    #[prost(message, optional, tag = "3")]
+    pub sdk_block: ::core::option::Option<Block>,
+}
+impl ::prost::Name for GetLatestBlockResponse {
+    const NAME: &'static str = "GetLatestBlockResponse";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.GetLatestBlockResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.GetLatestBlockResponse".into()
+    }
+}
+/// Block is tendermint type Block, with the Header proposer address
+/// field converted to bech32 string.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Block {
+    #[prost(message, optional, tag = "1")]
+    pub header: ::core::option::Option<Header>,
+}
+impl ::prost::Name for Block {
+    const NAME: &'static str = "Block";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.Block".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.Block".into()
+    }
+}
+/// Header defines the structure of a Tendermint block header.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Header {
+    #[prost(string, tag = "2")]
+    pub chain_id: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub height: i64,
+}
+impl ::prost::Name for Header {
+    const NAME: &'static str = "Header";
+    const PACKAGE: &'static str = "cosmos.base.tendermint.v1beta1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "cosmos.base.tendermint.v1beta1.Header".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/cosmos.base.tendermint.v1beta1.Header".into()
+    }
+}
+/// Generated client implementations.
+pub mod service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// Service defines the gRPC querier service for tendermint queries.
+    #[derive(Debug, Clone)]
+    pub struct ServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl ServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> ServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> ServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            ServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// GetLatestBlock returns the latest block.
+        pub async fn get_latest_block(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetLatestBlockRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetLatestBlockResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/cosmos.base.tendermint.v1beta1.Service/GetLatestBlock",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("cosmos.base.tendermint.v1beta1.Service", "GetLatestBlock"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
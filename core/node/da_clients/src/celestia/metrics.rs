@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+use celestia_types::nmt::Namespace;
+use vise::{
+    Buckets, Counter, EncodeLabelSet, EncodeLabelValue, Family, Gauge, Histogram, LabeledFamily,
+    Metrics, Unit,
+};
+use zksync_da_client::types::DAError;
+
+/// Buckets for `submit_latency` (from 0.1 to 120 seconds).
+const SUBMIT_LATENCIES: Buckets =
+    Buckets::values(&[0.1, 0.5, 1.0, 2.0, 5.0, 10.0, 30.0, 60.0, 120.0]);
+
+/// Label for blobs in namespaces that are not configured, e.g. ones referenced by legacy blob IDs.
+const OTHER_NAMESPACE_LABEL: &str = "other";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EncodeLabelValue)]
+#[metrics(rename_all = "snake_case")]
+pub(super) enum ErrorKind {
+    Retriable,
+    NonRetriable,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+pub(super) struct ErrorLabels {
+    kind: ErrorKind,
+    /// Set for errors related to blobs in a particular namespace.
+    namespace: Option<String>,
+}
+
+/// Returns the metrics label for the namespace: its hex ID if it's one of the `configured` namespaces,
+/// or a shared label otherwise, so that the label cardinality is bounded by the config.
+pub(super) fn namespace_label(configured: &[Namespace], namespace: Option<Namespace>) -> String {
+    match namespace {
+        Some(namespace) if configured.contains(&namespace) => hex::encode(namespace.as_bytes()),
+        _ => OTHER_NAMESPACE_LABEL.to_owned(),
+    }
+}
+
+#[derive(Debug, Metrics)]
+#[metrics(prefix = "celestia_da_client")]
+pub(super) struct CelestiaClientMetrics {
+    /// Latency of submitting a blob transaction, including retries and waiting for its confirmation.
+    #[metrics(buckets = SUBMIT_LATENCIES, unit = Unit::Seconds, labels = ["namespace"])]
+    pub submit_latency: LabeledFamily<String, Histogram<Duration>>,
+    /// Latency of a single inclusion data poll.
+    #[metrics(buckets = Buckets::LATENCIES, labels = ["namespace"])]
+    pub inclusion_poll_latency: LabeledFamily<String, Histogram<Duration>>,
+    /// Number of errors returned by the client.
+    pub errors: Family<ErrorLabels, Counter>,
+    /// Last observed balance of the account paying for the blobs, in `utia`.
+    pub balance: Gauge<u64>,
+}
+
+impl CelestiaClientMetrics {
+    /// Observes an error, labeled with the namespace label (see [`namespace_label()`]) if it's namespace-specific.
+    pub fn observe_error(&self, error: &DAError, namespace: Option<String>) {
+        let kind = if error.is_retriable() {
+            ErrorKind::Retriable
+        } else {
+            ErrorKind::NonRetriable
+        };
+        self.errors[&ErrorLabels { kind, namespace }].inc();
+    }
+}
+
+#[vise::register]
+pub(super) static METRICS: vise::Global<CelestiaClientMetrics> = vise::Global::new();
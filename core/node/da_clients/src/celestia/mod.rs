@@ -1,7 +1,12 @@
 mod client;
+mod metrics;
+mod retry;
 mod sdk;
 
-pub use self::client::CelestiaClient;
+pub use self::{
+    client::{pack_blobs, CelestiaBackend, CelestiaClient, PreparedBlobTx},
+    sdk::{CelestiaSdkError, CelestiaSigner, LocalSigner},
+};
 
 pub mod celestia_proto {
     include!("generated/celestia.blob.v1.rs");
@@ -21,6 +26,10 @@ pub mod cosmos {
             include!("generated/cosmos.base.node.v1beta1.rs");
         }
 
+        pub mod tendermint {
+            include!("generated/cosmos.base.tendermint.v1beta1.rs");
+        }
+
         pub mod v1beta1 {
             include!("generated/cosmos.base.v1beta1.rs");
         }
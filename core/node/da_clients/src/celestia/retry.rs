@@ -0,0 +1,129 @@
+use std::{
+    collections::hash_map::RandomState,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+use backon::{ExponentialBuilder, Retryable};
+use zksync_config::configs::da_client::celestia::CelestiaConfig;
+use zksync_da_client::types::DAError;
+
+/// Backoff settings for [`retry_da()`]. Delays grow exponentially from `min_delay` up to `max_delay`, with jitter.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct RetryPolicy {
+    /// The maximum number of retries after the first attempt.
+    pub max_retries: u32,
+    pub min_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Policy for quick read-only queries to the node, e.g. the latest height or the balance.
+    pub const QUERY: Self = Self {
+        max_retries: 2,
+        min_delay: Duration::from_millis(200),
+        max_delay: Duration::from_secs(2),
+    };
+
+    /// Policy for blob submissions, configured by `max_submit_retries` and `submit_retry_backoff_ms`.
+    pub fn submission(config: &CelestiaConfig) -> Self {
+        Self {
+            max_retries: config.max_submit_retries(),
+            min_delay: config.submit_retry_backoff(),
+            max_delay: Duration::from_secs(60),
+        }
+    }
+
+    fn backoff(&self) -> ExponentialBuilder {
+        ExponentialBuilder::default()
+            .with_min_delay(self.min_delay)
+            .with_max_delay(self.max_delay)
+            .with_max_times(self.max_retries as usize)
+            .with_jitter()
+    }
+}
+
+/// Runs the operation, retrying it according to the policy as long as it fails with a retriable error.
+/// The last error is returned if the retries are exhausted. `action` describes the operation in logs.
+///
+/// Logs of each call are prefixed with a short random ID, e.g. `[da a1b2] attempt 2/3 to submit blobs failed…`,
+/// so that concurrently retried operations can be told apart.
+pub(crate) async fn retry_da<T, Fut>(
+    policy: RetryPolicy,
+    action: &str,
+    operation: impl FnMut() -> Fut,
+) -> Result<T, DAError>
+where
+    Fut: Future<Output = Result<T, DAError>>,
+{
+    // `RandomState` is randomly seeded, so this is a cheap source of randomness for the ID.
+    let id = RandomState::new().build_hasher().finish() as u16;
+    let attempts = policy.max_retries + 1;
+    let mut attempt = 0;
+    let result = operation
+        .retry(&policy.backoff())
+        .when(DAError::is_retriable)
+        .notify(|err, delay| {
+            attempt += 1;
+            tracing::warn!(
+                err = %err.error,
+                "[da {id:04x}] attempt {attempt}/{attempts} to {action} failed, retrying in {delay:?}"
+            );
+        })
+        .await;
+    if let Err(err) = &result {
+        if err.is_retriable() {
+            tracing::warn!(
+                err = %err.error,
+                "[da {id:04x}] attempt {attempts}/{attempts} to {action} failed, giving up"
+            );
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+    use crate::utils::{to_non_retriable_da_error, to_retriable_da_error};
+
+    const FAST: RetryPolicy = RetryPolicy {
+        max_retries: 3,
+        min_delay: Duration::from_millis(1),
+        max_delay: Duration::from_millis(1),
+    };
+
+    #[tokio::test]
+    async fn only_retriable_errors_are_retried() {
+        let attempts = AtomicU32::new(0);
+        let result = retry_da(FAST, "test", || async {
+            match attempts.fetch_add(1, Ordering::Relaxed) {
+                0 | 1 => Err(to_retriable_da_error(anyhow::anyhow!("transient"))),
+                attempt => Ok(attempt),
+            }
+        })
+        .await;
+        assert_eq!(result.unwrap(), 2);
+
+        attempts.store(0, Ordering::Relaxed);
+        let result: Result<(), _> = retry_da(FAST, "test", || async {
+            attempts.fetch_add(1, Ordering::Relaxed);
+            Err(to_non_retriable_da_error(anyhow::anyhow!("fatal")))
+        })
+        .await;
+        assert!(!result.unwrap_err().is_retriable());
+        assert_eq!(attempts.load(Ordering::Relaxed), 1);
+
+        attempts.store(0, Ordering::Relaxed);
+        let result: Result<(), _> = retry_da(FAST, "test", || async {
+            attempts.fetch_add(1, Ordering::Relaxed);
+            Err(to_retriable_da_error(anyhow::anyhow!("transient")))
+        })
+        .await;
+        assert!(result.unwrap_err().is_retriable());
+        assert_eq!(attempts.load(Ordering::Relaxed), FAST.max_retries + 1);
+    }
+}
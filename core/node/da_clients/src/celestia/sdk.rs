@@ -1,14 +1,18 @@
 use std::{
+    error::Error,
     fmt::{Display, Formatter, Result},
     str::FromStr,
+    sync::Arc,
     time::{Duration, Instant},
 };
 
-use celestia_types::Blob;
+use anyhow::Context;
+use celestia_types::{blob::Commitment, nmt::Namespace, Blob};
 use prost::{bytes::Bytes, Message, Name};
-use secp256k1::{PublicKey, Secp256k1, SecretKey};
+use secp256k1::{ecdsa::Signature, PublicKey, Secp256k1, SecretKey};
 use sha2::Digest;
 use tonic::transport::Channel;
+use zksync_config::configs::da_client::celestia::CelestiaConfig;
 
 use super::{
     celestia_proto::{
@@ -25,6 +29,9 @@ use super::{
                 service_client::ServiceClient as MinGasPriceClient,
                 ConfigRequest as MinGasPriceRequest,
             },
+            tendermint::{
+                service_client::ServiceClient as TendermintClient, GetLatestBlockRequest,
+            },
             v1beta1::Coin,
         },
         crypto::secp256k1 as ec_proto,
@@ -43,34 +50,253 @@ pub const ADDRESS_LENGTH: usize = 20;
 const ACCOUNT_ADDRESS_PREFIX: bech32::Hrp = bech32::Hrp::parse_unchecked("celestia");
 const BLOB_TX_TYPE_ID: &str = "BLOB";
 
+/// Codespace of the errors defined by the Cosmos SDK itself, and the codes of the ones relevant for submissions.
+const SDK_CODESPACE: &str = "sdk";
+const SDK_ERR_INSUFFICIENT_FUNDS: u32 = 5;
+const SDK_ERR_TX_IN_MEMPOOL_CACHE: u32 = 19;
+const SDK_ERR_MEMPOOL_IS_FULL: u32 = 20;
+const SDK_ERR_TX_TOO_LARGE: u32 = 21;
+const SDK_ERR_WRONG_SEQUENCE: u32 = 32;
+
+pub(crate) type SdkResult<T> = std::result::Result<T, CelestiaSdkError>;
+
+/// Error returned when submitting blobs, classified so that the caller can decide how to handle it
+/// without inspecting the error message.
+#[derive(Debug)]
+pub enum CelestiaSdkError {
+    /// The node couldn't be reached or didn't respond in time.
+    Connection {
+        action: &'static str,
+        status: tonic::Status,
+    },
+    /// The account can't pay the fee. Goes away once the account is topped up.
+    InsufficientFunds(String),
+    /// The transaction was signed with an outdated account sequence, e.g. because of a concurrent transaction.
+    NonceConflict(String),
+    /// The blobs don't fit into a transaction.
+    BlobTooLarge(String),
+    /// The node failed a request with the given status.
+    Node {
+        action: &'static str,
+        status: tonic::Status,
+    },
+    /// The node rejected the transaction for another reason.
+    TxRejected {
+        code: u32,
+        codespace: String,
+        log: String,
+    },
+    Other(anyhow::Error),
+}
+
+impl CelestiaSdkError {
+    fn from_grpc_status(action: &'static str, status: tonic::Status) -> Self {
+        match status.code() {
+            // tonic reports request timeouts as cancelled
+            tonic::Code::Unavailable | tonic::Code::DeadlineExceeded | tonic::Code::Cancelled => {
+                Self::Connection { action, status }
+            }
+            _ => Self::Node { action, status },
+        }
+    }
+
+    fn from_tx_response(code: u32, codespace: String, log: String) -> Self {
+        if codespace != SDK_CODESPACE {
+            return Self::TxRejected {
+                code,
+                codespace,
+                log,
+            };
+        }
+        match code {
+            SDK_ERR_INSUFFICIENT_FUNDS => Self::InsufficientFunds(log),
+            SDK_ERR_WRONG_SEQUENCE => Self::NonceConflict(log),
+            SDK_ERR_TX_TOO_LARGE => Self::BlobTooLarge(log),
+            _ => Self::TxRejected {
+                code,
+                codespace,
+                log,
+            },
+        }
+    }
+
+    /// Returns `true` if the submission may succeed if retried right away, e.g. after a sequence number
+    /// mismatch caused by a concurrent transaction, or while the node is temporarily unavailable.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Connection { .. } | Self::NonceConflict(_) => true,
+            Self::Node { status, .. } => matches!(
+                status.code(),
+                tonic::Code::ResourceExhausted | tonic::Code::Aborted
+            ),
+            Self::TxRejected {
+                code, codespace, ..
+            } => {
+                codespace == SDK_CODESPACE
+                    && matches!(*code, SDK_ERR_TX_IN_MEMPOOL_CACHE | SDK_ERR_MEMPOOL_IS_FULL)
+            }
+            Self::InsufficientFunds(_) | Self::BlobTooLarge(_) | Self::Other(_) => false,
+        }
+    }
+}
+
+impl Display for CelestiaSdkError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            Self::Connection { action, status } => write!(
+                f,
+                "failed to {action}, node is unreachable, code: {}, message: {}",
+                status.code(),
+                status.message()
+            ),
+            Self::InsufficientFunds(log) => write!(f, "insufficient funds: {log}"),
+            Self::NonceConflict(log) => write!(f, "account sequence mismatch: {log}"),
+            Self::BlobTooLarge(log) => write!(f, "blob too large: {log}"),
+            Self::Node { action, status } => write!(
+                f,
+                "failed to {action}, code: {}, message: {}",
+                status.code(),
+                status.message()
+            ),
+            Self::TxRejected {
+                code,
+                codespace,
+                log,
+            } => write!(
+                f,
+                "transaction rejected, code: {code}, codespace: {codespace}, log: {log}"
+            ),
+            Self::Other(err) => Display::fmt(err, f),
+        }
+    }
+}
+
+impl Error for CelestiaSdkError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Connection { status, .. } | Self::Node { status, .. } => Some(status),
+            Self::Other(err) => err.source(),
+            _ => None,
+        }
+    }
+}
+
+/// Recovers the classified error if it was propagated as `anyhow::Error` by an internal method.
+impl From<anyhow::Error> for CelestiaSdkError {
+    fn from(err: anyhow::Error) -> Self {
+        err.downcast().unwrap_or_else(Self::Other)
+    }
+}
+
+/// Signs Celestia transactions, so that the signing key can be kept outside of the process, e.g. in an HSM or KMS.
+pub trait CelestiaSigner: Send + Sync {
+    /// Returns the public key of the account paying for the blobs.
+    fn public_key(&self) -> PublicKey;
+
+    /// Signs the given sign doc bytes. The bytes are expected to be SHA-256 hashed before signing.
+    fn sign(&self, bytes: &[u8]) -> anyhow::Result<Signature>;
+}
+
+/// Signer that holds the private key in memory.
+pub struct LocalSigner {
+    signing_key: SecretKey,
+}
+
+impl LocalSigner {
+    pub fn new(private_key: &str) -> anyhow::Result<Self> {
+        let signing_key = SecretKey::from_str(private_key)
+            .map_err(|e| anyhow::anyhow!("Failed to parse private key: {}", e))?;
+        Ok(Self { signing_key })
+    }
+}
+
+impl CelestiaSigner for LocalSigner {
+    fn public_key(&self) -> PublicKey {
+        self.signing_key.public_key(&Secp256k1::new())
+    }
+
+    fn sign(&self, bytes: &[u8]) -> anyhow::Result<Signature> {
+        let hashed_bytes: [u8; 32] = sha2::Sha256::digest(bytes).into();
+        Ok(Secp256k1::new().sign_ecdsa(
+            &secp256k1::Message::from_slice(&hashed_bytes[..]).unwrap(), // unwrap is safe here because we know the length of the hashed bytes
+            &self.signing_key,
+        ))
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct RawCelestiaClient {
     grpc_channel: Channel,
     address: String,
     chain_id: String,
-    signing_key: SecretKey,
+    signer: Arc<dyn CelestiaSigner>,
+    gas_price_policy: GasPricePolicy,
+    /// Account paying the transaction fees through a fee grant, if not the signer itself.
+    fee_granter: Option<String>,
 }
 
 impl RawCelestiaClient {
     pub(crate) fn new(
         grpc_channel: Channel,
-        private_key: String,
+        signer: Arc<dyn CelestiaSigner>,
         chain_id: String,
+        gas_price_policy: GasPricePolicy,
+        fee_granter: Option<String>,
     ) -> anyhow::Result<Self> {
-        let signing_key = SecretKey::from_str(&private_key)
-            .map_err(|e| anyhow::anyhow!("Failed to parse private key: {}", e))?;
-        let address = get_address(signing_key.public_key(&Secp256k1::new()))?;
+        let address = get_address(signer.public_key())?;
+        if let Some(fee_granter) = &fee_granter {
+            validate_address(fee_granter)
+                .with_context(|| format!("invalid fee granter address `{fee_granter}`"))?;
+        }
 
         Ok(Self {
             grpc_channel,
             address,
             chain_id,
-            signing_key,
+            signer,
+            gas_price_policy,
+            fee_granter,
         })
     }
 
-    /// Prepares a blob transaction for the given blobs.
-    pub(crate) async fn prepare(&self, blobs: Vec<Blob>) -> anyhow::Result<BlobTx> {
+    /// Returns a copy of the client that signs transactions for another chain, sharing the gRPC channel and signer.
+    pub(crate) fn with_chain_id(&self, chain_id: String) -> Self {
+        Self {
+            chain_id,
+            ..self.clone()
+        }
+    }
+
+    /// Returns the bech32 address of the account that pays for the blobs.
+    pub(crate) fn address(&self) -> &str {
+        &self.address
+    }
+
+    /// Checks that the node responds to a lightweight query.
+    pub(crate) async fn ping(&self) -> anyhow::Result<()> {
+        self.fetch_min_gas_price().await?;
+        Ok(())
+    }
+
+    /// Returns the height of the latest block known to the node.
+    pub(crate) async fn latest_height(&self) -> anyhow::Result<u64> {
+        let mut tendermint_client = TendermintClient::new(self.grpc_channel.clone());
+        let response = tendermint_client
+            .get_latest_block(GetLatestBlockRequest {})
+            .await
+            .map_err(|status| grpc_error("get latest block", status))?
+            .into_inner();
+        let header = response
+            .sdk_block
+            .or(response.block)
+            .and_then(|block| block.header)
+            .context("latest block has no header")?;
+        u64::try_from(header.height).context("negative block height")
+    }
+
+    /// Prepares a blob transaction for the given blobs. `attempt` is the zero-based number of the submission attempt,
+    /// used to escalate the gas price on resubmission.
+    pub(crate) async fn prepare(&self, blobs: Vec<Blob>, attempt: u32) -> SdkResult<BlobTx> {
         let (gas_per_blob_byte, tx_size_cost_per_byte, min_gas_price, base_account) = tokio::try_join!(
             self.get_gas_per_blob_byte(),
             self.fetch_tx_size_cost_per_byte(),
@@ -85,26 +311,43 @@ impl RawCelestiaClient {
             gas_per_blob_byte,
             tx_size_cost_per_byte,
         );
-        let fee = calculate_fee(min_gas_price, gas_limit);
+        let fee = calculate_fee(
+            min_gas_price,
+            self.gas_price_policy.multiplier(attempt),
+            gas_limit,
+        );
 
         let signed_tx = new_signed_tx(
             &msg_pay_for_blobs,
             &base_account,
             gas_limit,
             fee,
+            self.fee_granter.clone(),
             self.chain_id.clone(),
-            &self.signing_key,
-        );
+            self.signer.as_ref(),
+        )?;
 
         Ok(new_blob_tx(&signed_tx, blobs.iter()))
     }
 
+    /// Estimates the fee for a transaction with blobs of the given sizes at the current minimum gas price,
+    /// as paid on the first submission attempt.
+    pub(crate) async fn estimate_fee(&self, blob_sizes: &[u32]) -> anyhow::Result<u64> {
+        let (gas_per_blob_byte, tx_size_cost_per_byte, min_gas_price) = tokio::try_join!(
+            self.get_gas_per_blob_byte(),
+            self.fetch_tx_size_cost_per_byte(),
+            self.fetch_min_gas_price(),
+        )?;
+        let gas_limit = estimate_gas(blob_sizes, gas_per_blob_byte, tx_size_cost_per_byte);
+        Ok(calculate_fee(
+            min_gas_price,
+            self.gas_price_policy.multiplier(0),
+            gas_limit,
+        ))
+    }
+
     /// Submits the blob transaction to the node and returns the height of the block in which it was
-    pub(super) async fn submit(
-        &self,
-        blob_tx_hash: BlobTxHash,
-        blob_tx: BlobTx,
-    ) -> anyhow::Result<u64> {
+    pub(super) async fn submit(&self, blob_tx_hash: BlobTxHash, blob_tx: BlobTx) -> SdkResult<u64> {
         let mut client: TxClient<Channel> = TxClient::new(self.grpc_channel.clone());
         let hex_encoded_tx_hash = self.broadcast_tx(&mut client, blob_tx).await?;
         if hex_encoded_tx_hash != blob_tx_hash.clone().hex() {
@@ -129,13 +372,7 @@ impl RawCelestiaClient {
         let response = blob_query_client.params(QueryBlobParamsRequest {}).await;
 
         let params = response
-            .map_err(|status| {
-                anyhow::format_err!(
-                    "failed to get blob params, code: {}, message: {}",
-                    status.code(),
-                    status.message()
-                )
-            })?
+            .map_err(|status| grpc_error("get blob params", status))?
             .into_inner()
             .params
             .ok_or_else(|| anyhow::anyhow!("EmptyBlobParams"))?;
@@ -149,13 +386,7 @@ impl RawCelestiaClient {
         let response = auth_query_client.params(QueryAuthParamsRequest {}).await;
 
         let params = response
-            .map_err(|status| {
-                anyhow::format_err!(
-                    "failed to get auth params, code: {}, message: {}",
-                    status.code(),
-                    status.message()
-                )
-            })?
+            .map_err(|status| grpc_error("get auth params", status))?
             .into_inner()
             .params
             .ok_or_else(|| anyhow::anyhow!("EmptyAuthParams"))?;
@@ -169,13 +400,7 @@ impl RawCelestiaClient {
         let response = min_gas_price_client.config(MinGasPriceRequest {}).await;
 
         let min_gas_price_with_suffix = response
-            .map_err(|status| {
-                anyhow::format_err!(
-                    "failed to get price params, code: {}, message: {}",
-                    status.code(),
-                    status.message()
-                )
-            })?
+            .map_err(|status| grpc_error("get price params", status))?
             .into_inner()
             .minimum_gas_price;
 
@@ -205,13 +430,10 @@ impl RawCelestiaClient {
             address: self.address.clone(),
         };
 
-        let account_info = auth_query_client.account(request).await.map_err(|status| {
-            anyhow::anyhow!(
-                "failed to get account info, code: {}, message: {}",
-                status.code(),
-                status.message()
-            )
-        })?;
+        let account_info = auth_query_client
+            .account(request)
+            .await
+            .map_err(|status| grpc_error("get account info", status))?;
 
         let account_as_any = account_info
             .into_inner()
@@ -236,7 +458,7 @@ impl RawCelestiaClient {
         &self,
         client: &mut TxClient<Channel>,
         blob_tx: BlobTx,
-    ) -> anyhow::Result<String> {
+    ) -> SdkResult<String> {
         let request = BroadcastTxRequest {
             tx_bytes: Bytes::from(blob_tx.encode_to_vec()),
             mode: i32::from(BroadcastMode::Sync),
@@ -245,21 +467,14 @@ impl RawCelestiaClient {
         let mut tx_response = client
             .broadcast_tx(request)
             .await
-            .map_err(|status| {
-                anyhow::anyhow!(
-                    "failed to broadcast the tx, code: {}, message: {}",
-                    status.code(),
-                    status.message()
-                )
-            })?
+            .map_err(|status| grpc_error("broadcast the tx", status))?
             .into_inner()
             .tx_response
-            .ok_or_else(|| anyhow::anyhow!("empty broadcast tx response"))?;
+            .context("empty broadcast tx response")?;
 
         if tx_response.code != 0 {
-            return Err(anyhow::format_err!(
-                "failed to broadcast the tx, tx_hash: {}, code: {}, namespace: {}, log: {}",
-                tx_response.txhash,
+            tracing::debug!(tx_hash = %tx_response.txhash, "node rejected the blob transaction");
+            return Err(CelestiaSdkError::from_tx_response(
                 tx_response.code,
                 tx_response.codespace,
                 tx_response.raw_log,
@@ -345,11 +560,7 @@ impl RawCelestiaClient {
                     tracing::trace!(msg = status.message(), "transaction still pending");
                     return Ok(None);
                 }
-                return Err(anyhow::anyhow!(
-                    "failed to get tx, code: {}, message: {}",
-                    status.code(),
-                    status.message()
-                ));
+                return Err(grpc_error("get tx", status).into());
             }
         };
         let tx_response = ok_response
@@ -379,6 +590,145 @@ impl RawCelestiaClient {
     }
 }
 
+/// A client for the celestia-node JSON-RPC API, used to read blobs back.
+#[derive(Debug, Clone)]
+pub(crate) struct CelestiaNodeClient {
+    url: String,
+    auth_token: Option<String>,
+    http_client: reqwest::Client,
+}
+
+impl CelestiaNodeClient {
+    pub(crate) fn new(url: String, auth_token: Option<String>) -> Self {
+        Self {
+            url,
+            auth_token,
+            http_client: reqwest::Client::new(),
+        }
+    }
+
+    /// Fetches the blob with the given commitment from the given height and namespace.
+    /// Returns `None` if the node doesn't have the blob, e.g. because the height was pruned.
+    pub(crate) async fn get_blob(
+        &self,
+        height: u64,
+        namespace: Namespace,
+        commitment: Commitment,
+    ) -> anyhow::Result<Option<Blob>> {
+        let response = self
+            .send_request(
+                "blob.Get",
+                serde_json::json!([height, namespace, commitment]),
+            )
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            let message = error
+                .get("message")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default();
+            if message.contains("not found") {
+                return Ok(None);
+            }
+            anyhow::bail!("failed to get blob at height {height}: {error}");
+        }
+
+        let result = response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("empty blob.Get response"))?;
+        Ok(Some(serde_json::from_value(result)?))
+    }
+
+    /// Fetches all blobs posted to the given namespace at the given height.
+    pub(crate) async fn get_all_blobs(
+        &self,
+        height: u64,
+        namespace: Namespace,
+    ) -> anyhow::Result<Vec<Blob>> {
+        let response = self
+            .send_request("blob.GetAll", serde_json::json!([height, [namespace]]))
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            let message = error
+                .get("message")
+                .and_then(serde_json::Value::as_str)
+                .unwrap_or_default();
+            // Depending on the version, the node reports a height without blobs either as an error or as `null`.
+            if message.contains("not found") {
+                return Ok(vec![]);
+            }
+            anyhow::bail!("failed to get blobs at height {height}: {error}");
+        }
+
+        match response.get("result") {
+            None | Some(serde_json::Value::Null) => Ok(vec![]),
+            Some(result) => Ok(serde_json::from_value(result.clone())?),
+        }
+    }
+
+    /// Checks that the node responds to a lightweight query.
+    pub(crate) async fn ping(&self) -> anyhow::Result<()> {
+        let response = self
+            .send_request("header.LocalHead", serde_json::json!([]))
+            .await?;
+        if let Some(error) = response.get("error") {
+            anyhow::bail!("failed to get local head: {error}");
+        }
+        Ok(())
+    }
+
+    /// Fetches the balance of the given address, in `utia`.
+    pub(crate) async fn balance_for_address(&self, address: &str) -> anyhow::Result<u64> {
+        let response = self
+            .send_request("state.BalanceForAddress", serde_json::json!([address]))
+            .await?;
+
+        if let Some(error) = response.get("error") {
+            anyhow::bail!("failed to get balance for {address}: {error}");
+        }
+
+        let result = response
+            .get("result")
+            .ok_or_else(|| anyhow::anyhow!("empty state.BalanceForAddress response"))?;
+        let denom = result.get("denom").and_then(serde_json::Value::as_str);
+        anyhow::ensure!(
+            denom == Some(UNITS_SUFFIX),
+            "unexpected balance denomination: {denom:?}"
+        );
+        let amount = result
+            .get("amount")
+            .and_then(serde_json::Value::as_str)
+            .ok_or_else(|| anyhow::anyhow!("missing balance amount"))?;
+        Ok(amount.parse()?)
+    }
+
+    async fn send_request(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+    ) -> anyhow::Result<serde_json::Value> {
+        let request = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": method,
+            "params": params,
+        });
+
+        let mut request_builder = self.http_client.post(&self.url).json(&request);
+        if let Some(auth_token) = &self.auth_token {
+            request_builder = request_builder.bearer_auth(auth_token);
+        }
+        Ok(request_builder.send().await?.json().await?)
+    }
+}
+
+/// Converts a gRPC status into an error classified by the status code.
+fn grpc_error(action: &'static str, status: tonic::Status) -> CelestiaSdkError {
+    CelestiaSdkError::from_grpc_status(action, status)
+}
+
 /// Returns a `BlobTx` for the given signed tx and blobs.
 fn new_blob_tx<'a>(signed_tx: &Tx, blobs: impl Iterator<Item = &'a Blob>) -> BlobTx {
     let blobs = blobs
@@ -402,9 +752,10 @@ fn new_signed_tx(
     base_account: &BaseAccount,
     gas_limit: u64,
     fee: u64,
+    fee_granter: Option<String>,
     chain_id: String,
-    signing_key: &SecretKey,
-) -> Tx {
+    signer: &dyn CelestiaSigner,
+) -> anyhow::Result<Tx> {
     const SIGNING_MODE_INFO: Option<ModeInfo> = Some(ModeInfo {
         sum: Some(Sum::Single(Single { mode: 1 })),
     });
@@ -416,16 +767,12 @@ fn new_signed_tx(
     let fee = Fee {
         amount: vec![fee_coin],
         gas_limit,
+        granter: fee_granter.unwrap_or_default(),
         ..Fee::default()
     };
 
     let public_key = ec_proto::PubKey {
-        key: Bytes::from(
-            signing_key
-                .public_key(&Secp256k1::new())
-                .serialize()
-                .to_vec(),
-        ),
+        key: Bytes::from(signer.public_key().serialize().to_vec()),
     };
     let public_key_as_any = pbjson_types::Any {
         type_url: ec_proto::PubKey::type_url(),
@@ -457,72 +804,98 @@ fn new_signed_tx(
         account_number: base_account.account_number,
     }
     .encode_to_vec();
-    let hashed_bytes: [u8; 32] = sha2::Sha256::digest(bytes_to_sign).into();
-    let signature = secp256k1::Secp256k1::new().sign_ecdsa(
-        &secp256k1::Message::from_slice(&hashed_bytes[..]).unwrap(), // unwrap is safe here because we know the length of the hashed bytes
-        signing_key,
-    );
-    Tx {
+    let signature = signer
+        .sign(&bytes_to_sign)
+        .map_err(|err| err.context("failed to sign the tx"))?;
+    Ok(Tx {
         body: Some(tx_body),
         auth_info: Some(auth_info),
         signatures: vec![Bytes::from(signature.serialize_compact().to_vec())],
+    })
+}
+
+/// Determines the gas price paid for a transaction relative to the minimum gas price of the node.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct GasPricePolicy {
+    /// The multiplier used on the first submission attempt.
+    pub multiplier: f64,
+    /// The factor the multiplier is multiplied by on each following attempt.
+    pub escalation_factor: f64,
+    /// The cap on the escalated multiplier. Doesn't lower the multiplier of the first attempt.
+    pub max_multiplier: f64,
+}
+
+impl GasPricePolicy {
+    pub(crate) fn from_config(config: &CelestiaConfig) -> Self {
+        Self {
+            multiplier: config.gas_price_multiplier(),
+            escalation_factor: config.gas_price_escalation_factor(),
+            max_multiplier: config.max_gas_price_multiplier(),
+        }
+    }
+
+    /// Returns the gas price multiplier for the submission attempt with the given zero-based number.
+    pub(crate) fn multiplier(&self, attempt: u32) -> f64 {
+        let escalated = self.multiplier * self.escalation_factor.powi(attempt as i32);
+        escalated.min(self.max_multiplier.max(self.multiplier))
     }
 }
 
 /// Returns the fee for the signed tx.
-fn calculate_fee(min_gas_price: f64, gas_limit: u64) -> u64 {
-    let calculated_fee = (min_gas_price * gas_limit as f64).ceil() as u64;
+fn calculate_fee(min_gas_price: f64, gas_price_multiplier: f64, gas_limit: u64) -> u64 {
+    let calculated_fee = (min_gas_price * gas_price_multiplier * gas_limit as f64).ceil() as u64;
     tracing::info!(
-        "calculated fee: {}, min_gas_price: {}, gas_limit: {}",
+        "calculated fee: {}, min_gas_price: {}, gas_price_multiplier: {}, gas_limit: {}",
         calculated_fee,
         min_gas_price,
+        gas_price_multiplier,
         gas_limit
     );
 
     calculated_fee
 }
 
-fn estimate_gas(blob_sizes: &[u32], gas_per_blob_byte: u32, tx_size_cost_per_byte: u64) -> u64 {
-    // From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/pkg/appconsts/global_consts.go#L28
-    const SHARE_SIZE: u64 = 512;
-    // From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/pkg/appconsts/global_consts.go#L55
-    const CONTINUATION_COMPACT_SHARE_CONTENT_SIZE: u32 = 482;
-    // From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/pkg/appconsts/global_consts.go#L59
-    const FIRST_SPARSE_SHARE_CONTENT_SIZE: u32 = 478;
-    // From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/x/blob/types/payforblob.go#L40
-    const PFB_GAS_FIXED_COST: u64 = 75_000;
-    // From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/x/blob/types/payforblob.go#L44
-    const BYTES_PER_BLOB_INFO: u64 = 70;
-
-    // From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/pkg/shares/share_sequence.go#L126
-    //
-    // `blob_len` is the size in bytes of one blob's `data` field.
-    fn sparse_shares_needed(blob_len: u32) -> u64 {
-        if blob_len == 0 {
-            return 0;
-        }
+// From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/pkg/appconsts/global_consts.go#L28
+pub(super) const SHARE_SIZE: u64 = 512;
+// From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/pkg/appconsts/global_consts.go#L55
+const CONTINUATION_COMPACT_SHARE_CONTENT_SIZE: u32 = 482;
+// From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/pkg/appconsts/global_consts.go#L59
+const FIRST_SPARSE_SHARE_CONTENT_SIZE: u32 = 478;
+// From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/x/blob/types/payforblob.go#L40
+const PFB_GAS_FIXED_COST: u64 = 75_000;
+// From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/x/blob/types/payforblob.go#L44
+pub(super) const BYTES_PER_BLOB_INFO: u64 = 70;
+
+// From https://github.com/celestiaorg/celestia-app/blob/v1.4.0/pkg/shares/share_sequence.go#L126
+//
+// `blob_len` is the size in bytes of one blob's `data` field.
+pub(super) fn sparse_shares_needed(blob_len: u32) -> u64 {
+    if blob_len == 0 {
+        return 0;
+    }
 
-        if blob_len < FIRST_SPARSE_SHARE_CONTENT_SIZE {
-            return 1;
-        }
+    if blob_len < FIRST_SPARSE_SHARE_CONTENT_SIZE {
+        return 1;
+    }
 
-        // Use `u64` here to avoid overflow while adding below.
-        let mut bytes_available = u64::from(FIRST_SPARSE_SHARE_CONTENT_SIZE);
-        let mut shares_needed = 1_u64;
-        while bytes_available < u64::from(blob_len) {
-            bytes_available = bytes_available
-                .checked_add(u64::from(CONTINUATION_COMPACT_SHARE_CONTENT_SIZE))
-                .expect(
-                    "this can't overflow, as on each iteration `bytes_available < u32::MAX`, and \
-                     we're adding at most `u32::MAX` to it",
-                );
-            shares_needed = shares_needed.checked_add(1).expect(
-                "this can't overflow, as the loop cannot execute for `u64::MAX` iterations",
+    // Use `u64` here to avoid overflow while adding below.
+    let mut bytes_available = u64::from(FIRST_SPARSE_SHARE_CONTENT_SIZE);
+    let mut shares_needed = 1_u64;
+    while bytes_available < u64::from(blob_len) {
+        bytes_available = bytes_available
+            .checked_add(u64::from(CONTINUATION_COMPACT_SHARE_CONTENT_SIZE))
+            .expect(
+                "this can't overflow, as on each iteration `bytes_available < u32::MAX`, and \
+                 we're adding at most `u32::MAX` to it",
             );
-        }
-        shares_needed
+        shares_needed = shares_needed
+            .checked_add(1)
+            .expect("this can't overflow, as the loop cannot execute for `u64::MAX` iterations");
     }
+    shares_needed
+}
 
+fn estimate_gas(blob_sizes: &[u32], gas_per_blob_byte: u32, tx_size_cost_per_byte: u64) -> u64 {
     let total_shares_used: u64 = blob_sizes.iter().copied().map(sparse_shares_needed).sum();
     let blob_count = blob_sizes.len().try_into().unwrap_or(u64::MAX);
 
@@ -539,7 +912,7 @@ fn estimate_gas(blob_sizes: &[u32], gas_per_blob_byte: u32, tx_size_cost_per_byt
 }
 
 /// Prepares a `MsgPayForBlobs` message for the given blobs.
-fn new_msg_pay_for_blobs(blobs: &[Blob], signer: String) -> anyhow::Result<MsgPayForBlobs> {
+fn new_msg_pay_for_blobs(blobs: &[Blob], signer: String) -> SdkResult<MsgPayForBlobs> {
     let mut blob_sizes = Vec::with_capacity(blobs.len());
     let mut namespaces = Vec::with_capacity(blobs.len());
     let mut share_commitments = Vec::with_capacity(blobs.len());
@@ -555,9 +928,9 @@ fn new_msg_pay_for_blobs(blobs: &[Blob], signer: String) -> anyhow::Result<MsgPa
         .into_iter()
         .map(|blob_size| {
             u32::try_from(blob_size)
-                .map_err(|_| anyhow::anyhow!("blob too large, size: {}", blob_size))
+                .map_err(|_| CelestiaSdkError::BlobTooLarge(format!("size: {blob_size}")))
         })
-        .collect::<anyhow::Result<_, _>>()?;
+        .collect::<SdkResult<_>>()?;
 
     Ok(MsgPayForBlobs {
         signer,
@@ -582,6 +955,21 @@ fn get_address(public_key: PublicKey) -> anyhow::Result<String> {
     )?)
 }
 
+/// Checks that the address is a bech32-encoded Celestia account address.
+fn validate_address(address: &str) -> anyhow::Result<()> {
+    let (hrp, bytes) = bech32::decode(address).context("address is not valid bech32")?;
+    anyhow::ensure!(
+        hrp == ACCOUNT_ADDRESS_PREFIX,
+        "expected the `{ACCOUNT_ADDRESS_PREFIX}` prefix, got `{hrp}`"
+    );
+    anyhow::ensure!(
+        bytes.len() == ADDRESS_LENGTH,
+        "expected {ADDRESS_LENGTH} bytes, got {}",
+        bytes.len()
+    );
+    Ok(())
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct BlobTxHash([u8; 32]);
 
@@ -600,3 +988,90 @@ impl Display for BlobTxHash {
         write!(formatter, "{}", hex::encode(self.0))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gas_price_multiplier_is_applied_and_capped() {
+        let policy = GasPricePolicy {
+            multiplier: 1.5,
+            escalation_factor: 2.0,
+            max_multiplier: 5.0,
+        };
+        assert_eq!(policy.multiplier(0), 1.5);
+        assert_eq!(policy.multiplier(1), 3.0);
+        assert_eq!(policy.multiplier(2), 5.0);
+        assert_eq!(policy.multiplier(10), 5.0);
+
+        assert_eq!(calculate_fee(0.002, policy.multiplier(0), 100_000), 300);
+        assert_eq!(calculate_fee(0.002, policy.multiplier(1), 100_000), 600);
+        assert_eq!(calculate_fee(0.002, policy.multiplier(5), 100_000), 1_000);
+
+        // The cap only limits escalation and doesn't lower the configured multiplier.
+        let policy = GasPricePolicy {
+            multiplier: 4.0,
+            escalation_factor: 1.5,
+            max_multiplier: 3.0,
+        };
+        assert_eq!(policy.multiplier(0), 4.0);
+        assert_eq!(policy.multiplier(3), 4.0);
+    }
+
+    #[test]
+    fn addresses_are_validated() {
+        let public_key = LocalSigner::new(&"11".repeat(32)).unwrap().public_key();
+        validate_address(&get_address(public_key).unwrap()).unwrap();
+
+        let cosmos_address = bech32::encode::<bech32::Bech32>(
+            bech32::Hrp::parse_unchecked("cosmos"),
+            &[1; ADDRESS_LENGTH],
+        )
+        .unwrap();
+        let short_address =
+            bech32::encode::<bech32::Bech32>(ACCOUNT_ADDRESS_PREFIX, &[1; 10]).unwrap();
+        for address in ["", "celestia1invalid", &cosmos_address, &short_address] {
+            assert!(validate_address(address).is_err(), "{address}");
+        }
+    }
+
+    #[test]
+    fn sdk_errors_are_classified() {
+        let tx_error = |code, codespace: &str| {
+            CelestiaSdkError::from_tx_response(code, codespace.to_owned(), "log".to_owned())
+        };
+        assert!(matches!(
+            tx_error(SDK_ERR_INSUFFICIENT_FUNDS, "sdk"),
+            CelestiaSdkError::InsufficientFunds(_)
+        ));
+        assert!(matches!(
+            tx_error(SDK_ERR_WRONG_SEQUENCE, "sdk"),
+            CelestiaSdkError::NonceConflict(_)
+        ));
+        assert!(matches!(
+            tx_error(SDK_ERR_TX_TOO_LARGE, "sdk"),
+            CelestiaSdkError::BlobTooLarge(_)
+        ));
+        // Codes are only meaningful within their codespace.
+        assert!(matches!(
+            tx_error(SDK_ERR_INSUFFICIENT_FUNDS, "blob"),
+            CelestiaSdkError::TxRejected { .. }
+        ));
+
+        assert!(tx_error(SDK_ERR_WRONG_SEQUENCE, "sdk").is_transient());
+        assert!(tx_error(SDK_ERR_MEMPOOL_IS_FULL, "sdk").is_transient());
+        assert!(!tx_error(SDK_ERR_INSUFFICIENT_FUNDS, "sdk").is_transient());
+        assert!(!tx_error(SDK_ERR_MEMPOOL_IS_FULL, "blob").is_transient());
+
+        let unreachable = grpc_error("ping", tonic::Status::unavailable("connection refused"));
+        assert!(matches!(unreachable, CelestiaSdkError::Connection { .. }));
+        assert!(unreachable.is_transient());
+        let invalid = grpc_error("ping", tonic::Status::invalid_argument("bad request"));
+        assert!(!invalid.is_transient());
+
+        // The classification survives propagation through `anyhow`.
+        let propagated = CelestiaSdkError::from(anyhow::Error::from(unreachable));
+        assert!(matches!(propagated, CelestiaSdkError::Connection { .. }));
+    }
+}
@@ -1,6 +1,8 @@
 pub mod avail;
 pub mod celestia;
 pub mod eigen;
+#[cfg(any(test, feature = "test-utils"))]
+pub mod mock;
 pub mod no_da;
 pub mod object_store;
 mod utils;
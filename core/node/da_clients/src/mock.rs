@@ -0,0 +1,232 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+use async_trait::async_trait;
+use sha2::Digest;
+use zksync_da_client::{
+    types::{DAError, DispatchResponse, InclusionData},
+    DataAvailabilityClient,
+};
+
+/// Kind of error the [`MockDaClient`] returns while error simulation is enabled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MockDaError {
+    Retriable,
+    NonRetriable,
+}
+
+#[derive(Debug)]
+struct MockBlob {
+    batch_number: u32,
+    data: Vec<u8>,
+    polls: u32,
+}
+
+#[derive(Debug, Default)]
+struct MockDaState {
+    blobs: HashMap<String, MockBlob>,
+    balance: u64,
+    error: Option<MockDaError>,
+}
+
+/// In-memory implementation of the `DataAvailabilityClient` trait for tests.
+///
+/// Dispatched blobs get a blob ID derived from the batch number and the data, and are reported as included
+/// after `polls_until_included` calls to `get_inclusion_data()`. Clones share the same state, so the client
+/// can be configured and inspected after it's passed to the code under test.
+#[derive(Debug, Clone, Default)]
+pub struct MockDaClient {
+    state: Arc<Mutex<MockDaState>>,
+    polls_until_included: u32,
+    blob_size_limit: Option<usize>,
+}
+
+impl MockDaClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of `get_inclusion_data()` calls returning `None` before a blob is reported as included.
+    pub fn with_polls_until_included(mut self, polls: u32) -> Self {
+        self.polls_until_included = polls;
+        self
+    }
+
+    pub fn with_blob_size_limit(mut self, limit: usize) -> Self {
+        self.blob_size_limit = Some(limit);
+        self
+    }
+
+    fn state(&self) -> MutexGuard<'_, MockDaState> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Makes all following calls fail with the given kind of error, or succeed again if `None` is passed.
+    pub fn set_error(&self, error: Option<MockDaError>) {
+        self.state().error = error;
+    }
+
+    pub fn set_balance(&self, balance: u64) {
+        self.state().balance = balance;
+    }
+
+    /// Returns the balance set by [`Self::set_balance()`].
+    pub fn balance(&self) -> Result<u64, DAError> {
+        let state = self.state();
+        Self::check_error(&state)?;
+        Ok(state.balance)
+    }
+
+    /// Returns the data dispatched for the given batch, if any.
+    pub fn dispatched_data(&self, batch_number: u32) -> Option<Vec<u8>> {
+        self.state()
+            .blobs
+            .values()
+            .find(|blob| blob.batch_number == batch_number)
+            .map(|blob| blob.data.clone())
+    }
+
+    /// Returns the number of dispatched blobs.
+    pub fn dispatched_count(&self) -> usize {
+        self.state().blobs.len()
+    }
+
+    fn check_error(state: &MockDaState) -> Result<(), DAError> {
+        match state.error {
+            None => Ok(()),
+            Some(kind) => Err(DAError {
+                error: anyhow::anyhow!("simulated {kind:?} error"),
+                is_retriable: kind == MockDaError::Retriable,
+            }),
+        }
+    }
+
+    fn blob_id(batch_number: u32, data: &[u8]) -> String {
+        format!("{batch_number}:{}", hex::encode(sha2::Sha256::digest(data)))
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityClient for MockDaClient {
+    async fn dispatch_blob(
+        &self,
+        batch_number: u32,
+        data: Vec<u8>,
+    ) -> Result<DispatchResponse, DAError> {
+        let mut state = self.state();
+        Self::check_error(&state)?;
+        if let Some(limit) = self.blob_size_limit {
+            if data.len() > limit {
+                return Err(DAError {
+                    error: anyhow::anyhow!(
+                        "blob is too large: {} bytes, while the limit is {limit} bytes",
+                        data.len()
+                    ),
+                    is_retriable: false,
+                });
+            }
+        }
+
+        let blob_id = Self::blob_id(batch_number, &data);
+        state.blobs.insert(
+            blob_id.clone(),
+            MockBlob {
+                batch_number,
+                data,
+                polls: 0,
+            },
+        );
+        Ok(DispatchResponse { blob_id })
+    }
+
+    async fn get_inclusion_data(&self, blob_id: &str) -> Result<Option<InclusionData>, DAError> {
+        let polls_until_included = self.polls_until_included;
+        let mut state = self.state();
+        Self::check_error(&state)?;
+        let blob = state.blobs.get_mut(blob_id).ok_or_else(|| DAError {
+            error: anyhow::anyhow!("unknown blob ID: {blob_id}"),
+            is_retriable: false,
+        })?;
+
+        if blob.polls < polls_until_included {
+            blob.polls += 1;
+            return Ok(None);
+        }
+        Ok(Some(InclusionData {
+            data: blob_id.as_bytes().to_vec(),
+        }))
+    }
+
+    fn clone_boxed(&self) -> Box<dyn DataAvailabilityClient> {
+        Box::new(self.clone())
+    }
+
+    fn blob_size_limit(&self) -> Option<usize> {
+        self.blob_size_limit
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zksync_da_client::types::InclusionStatus;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn blob_is_included_after_configured_polls() {
+        let client = MockDaClient::new().with_polls_until_included(2);
+        let blob_id = client
+            .dispatch_blob(1, vec![1, 2, 3])
+            .await
+            .unwrap()
+            .blob_id;
+        assert_eq!(client.dispatched_data(1), Some(vec![1, 2, 3]));
+
+        assert!(client.get_inclusion_data(&blob_id).await.unwrap().is_none());
+        assert!(client.get_inclusion_data(&blob_id).await.unwrap().is_none());
+        assert!(client.get_inclusion_data(&blob_id).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn inclusion_status() {
+        let client = MockDaClient::new().with_polls_until_included(1);
+        let blob_id = client.dispatch_blob(1, vec![1]).await.unwrap().blob_id;
+
+        let status = client.get_inclusion_status(&blob_id).await.unwrap();
+        assert!(matches!(status, InclusionStatus::Pending));
+        let status = client.get_inclusion_status(&blob_id).await.unwrap();
+        assert!(matches!(status, InclusionStatus::Included(_)));
+        let status = client.get_inclusion_status("unknown").await.unwrap();
+        assert!(
+            matches!(&status, InclusionStatus::Failed(reason) if reason.contains("unknown blob ID"))
+        );
+        assert!(!status.into_inclusion_data().unwrap_err().is_retriable());
+
+        client.set_error(Some(MockDaError::Retriable));
+        let Err(err) = client.get_inclusion_status(&blob_id).await else {
+            panic!("retriable errors must be propagated");
+        };
+        assert!(err.is_retriable());
+    }
+
+    #[tokio::test]
+    async fn simulated_errors() {
+        let client = MockDaClient::new();
+        client.set_balance(100);
+        assert_eq!(client.balance().unwrap(), 100);
+
+        client.set_error(Some(MockDaError::Retriable));
+        let Err(err) = client.dispatch_blob(1, vec![]).await else {
+            panic!("dispatch unexpectedly succeeded");
+        };
+        assert!(err.is_retriable());
+        client.set_error(Some(MockDaError::NonRetriable));
+        assert!(!client.balance().unwrap_err().is_retriable());
+
+        client.set_error(None);
+        let blob_id = client.dispatch_blob(1, vec![]).await.unwrap().blob_id;
+        assert!(client.get_inclusion_data(&blob_id).await.unwrap().is_some());
+    }
+}
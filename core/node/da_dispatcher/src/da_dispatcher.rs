@@ -1,4 +1,9 @@
-use std::{future::Future, time::Duration};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
 
 use anyhow::Context;
 use chrono::Utc;
@@ -19,6 +24,8 @@ pub struct DataAvailabilityDispatcher {
     client: Box<dyn DataAvailabilityClient>,
     pool: ConnectionPool<Core>,
     config: DADispatcherConfig,
+    /// Time of the first dispatch attempt for the batches that are not dispatched yet.
+    first_dispatch_attempts: Mutex<HashMap<L1BatchNumber, Instant>>,
 }
 
 impl DataAvailabilityDispatcher {
@@ -31,6 +38,7 @@ impl DataAvailabilityDispatcher {
             pool,
             config,
             client,
+            first_dispatch_attempts: Mutex::default(),
         }
     }
 
@@ -82,8 +90,20 @@ impl DataAvailabilityDispatcher {
         drop(conn);
 
         for batch in batches {
+            let first_attempt_at = *self
+                .first_dispatch_attempts
+                .lock()
+                .unwrap()
+                .entry(batch.l1_batch_number)
+                .or_insert_with(Instant::now);
+            let retry_policy = RetryPolicy {
+                max_retries: self.config.max_retries(),
+                max_retry_age: self.config.max_retry_age(),
+                first_attempt_at,
+            };
+
             let dispatch_latency = METRICS.blob_dispatch_latency.start();
-            let dispatch_response = retry(self.config.max_retries(), batch.l1_batch_number, || {
+            let dispatch_response = retry(retry_policy, batch.l1_batch_number, || {
                 self.client
                     .dispatch_blob(batch.l1_batch_number.0, batch.pubdata.clone())
             })
@@ -96,6 +116,10 @@ impl DataAvailabilityDispatcher {
                 )
             })?;
             let dispatch_latency_duration = dispatch_latency.observe();
+            self.first_dispatch_attempts
+                .lock()
+                .unwrap()
+                .remove(&batch.l1_batch_number);
 
             let sent_at = Utc::now().naive_utc();
 
@@ -181,8 +205,17 @@ impl DataAvailabilityDispatcher {
     }
 }
 
-async fn retry<T, Fut, F>(
+/// Limits on how long a blob dispatch is retried.
+#[derive(Debug, Clone, Copy)]
+struct RetryPolicy {
     max_retries: u16,
+    /// If set, retriable errors are treated as fatal once this much time has passed since `first_attempt_at`.
+    max_retry_age: Option<Duration>,
+    first_attempt_at: Instant,
+}
+
+async fn retry<T, Fut, F>(
+    policy: RetryPolicy,
     batch_number: L1BatchNumber,
     mut f: F,
 ) -> Result<T, DAError>
@@ -190,6 +223,7 @@ where
     Fut: Future<Output = Result<T, DAError>>,
     F: FnMut() -> Fut,
 {
+    let max_retries = policy.max_retries;
     let mut retries = 1;
     let mut backoff_secs = 1;
     loop {
@@ -202,6 +236,18 @@ where
                 if !err.is_retriable() || retries > max_retries {
                     return Err(err);
                 }
+                if let Some(max_retry_age) = policy.max_retry_age {
+                    let age = policy.first_attempt_at.elapsed();
+                    if age > max_retry_age {
+                        return Err(DAError {
+                            error: err.error.context(format!(
+                                "giving up on dispatching batch {batch_number}: it has been retried \
+                                 for {age:?}, which exceeds the maximum retry age of {max_retry_age:?}"
+                            )),
+                            is_retriable: false,
+                        });
+                    }
+                }
 
                 retries += 1;
                 let sleep_duration = Duration::from_secs(backoff_secs)
@@ -1,31 +1,143 @@
 use std::{
+    borrow::Cow,
+    collections::hash_map::RandomState,
     ffi::OsStr,
     fmt::{Display, Formatter},
-    io,
-    process::{Command, Output, Stdio},
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    io::{self, BufRead, BufReader, Read, Write},
+    marker::PhantomData,
+    path::{Path, PathBuf},
+    process::{Child, ChildStderr, ChildStdin, ChildStdout, Command, ExitStatus, Output, Stdio},
     string::FromUtf8Error,
+    sync::{Arc, Mutex, PoisonError},
+    thread,
+    time::{Duration, Instant},
 };
 
 use console::style;
+use serde::de::DeserializeOwned;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWriteExt};
 
 use crate::{
     config::global_config,
     logger::{self},
+    spinner::Spinner,
 };
 
 /// A wrapper around [`xshell::Cmd`] that allows for improved error handling,
 /// and verbose logging.
 #[derive(Debug)]
 pub struct Cmd<'a> {
-    inner: xshell::Cmd<'a>,
+    /// The command converted from [`xshell::Cmd`] upfront. `xshell` can only convert it by value, while
+    /// [`Cmd::run_with_output`] runs the command without consuming `self`.
+    command: Command,
+    /// Command line as displayed by [`xshell::Cmd`], which hides it for secret commands.
+    display: String,
+    shell: PhantomData<&'a xshell::Shell>,
     force_run: bool,
+    dry_run: bool,
     // For resume functionality we must pipe the output, otherwise it only shows less information
     piped_std_err: bool,
+    current_dir: Option<PathBuf>,
+    spawn_options: SpawnOptions,
+    retries: u32,
+    retry_backoff: Duration,
+    redactor: Redactor,
+    output_format: OutputFormat,
+    /// Message of the spinner shown while a quiet command runs.
+    spinner_msg: Option<String>,
+    /// Whether output assertions accept a non-zero exit status.
+    allow_failure: bool,
+    /// Whether the environment of the caller is not inherited.
+    env_clear: bool,
 }
 
+/// Layout of command output in logs.
+#[derive(Debug, Clone, Copy)]
+struct OutputFormat {
+    indent: usize,
+    wrap: usize,
+    /// Maximum number of bytes of each output stream that is logged.
+    max_len: usize,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        Self {
+            indent: 4,
+            wrap: 120,
+            max_len: DEFAULT_MAX_LOGGED_OUTPUT_LEN,
+        }
+    }
+}
+
+/// Settings applied when the command is spawned, on top of the [`Command`] itself.
+#[derive(Debug, Default)]
+struct SpawnOptions {
+    timeout: Option<Duration>,
+    stdin: Option<Vec<u8>>,
+    line_handler: Option<LineHandler>,
+    /// Whether captured output is also written to the terminal as it arrives.
+    tee: bool,
+}
+
+/// Callback invoked for every line of captured output. It's shared by the stdout and stderr readers.
+#[derive(Clone)]
+struct LineHandler(Arc<Mutex<LineHandlerFn>>);
+
+type LineHandlerFn = Box<dyn FnMut(&str) + Send>;
+
+impl std::fmt::Debug for LineHandler {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LineHandler").finish_non_exhaustive()
+    }
+}
+
+impl LineHandler {
+    fn call(&self, line: &[u8]) {
+        let line = String::from_utf8_lossy(line);
+        let mut handler = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        handler(line.trim_end_matches(['\n', '\r']));
+    }
+}
+
+/// Masks secrets in logged command lines and output.
+#[derive(Debug, Clone, Default)]
+struct Redactor {
+    secrets: Vec<String>,
+}
+
+impl Redactor {
+    const MASK: &'static str = "****";
+
+    fn redact(&self, text: &str) -> String {
+        self.secrets
+            .iter()
+            .filter(|secret| !secret.is_empty())
+            .fold(text.to_owned(), |text, secret| {
+                text.replace(secret.as_str(), Self::MASK)
+            })
+    }
+}
+
+/// Maximum number of characters of stdout included in the error if it can't be parsed as JSON.
+const JSON_ERROR_SNIPPET_LEN: usize = 200;
+
+/// Default maximum number of bytes of stdout and stderr each that is logged, see [`Cmd::with_max_logged_output`].
+const DEFAULT_MAX_LOGGED_OUTPUT_LEN: usize = 1 << 20;
+
+/// How often a command with a timeout is checked for completion.
+const TIMEOUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
+
+/// How long a killed process group is given to exit after SIGTERM before it's killed with SIGKILL.
+const KILL_GRACE_PERIOD: Duration = Duration::from_secs(2);
+
 #[derive(thiserror::Error, Debug)]
 pub struct CmdError {
     pub stderr: Option<String>,
+    /// Exit code of the command, if it ran to completion (i.e., wasn't killed by a signal or failed to start).
+    pub exit_code: Option<i32>,
     pub source: anyhow::Error,
 }
 
@@ -39,10 +151,109 @@ impl Display for CmdError {
     }
 }
 
+/// Error returned when a command doesn't finish within its timeout and is killed.
+#[derive(thiserror::Error, Debug)]
+#[error("Command timed out after {timeout:?} and was killed: {command}")]
+pub struct CmdTimeoutError {
+    pub command: String,
+    pub timeout: Duration,
+}
+
+/// Error returned when the program to run can't be found, i.e. it's not installed or not in `PATH`.
+#[derive(thiserror::Error, Debug)]
+#[error("`{program}` was not found, install it and make sure it's available in your PATH")]
+pub struct ProgramNotFoundError {
+    pub program: String,
+}
+
+/// Broad category of a command failure, used to suggest a remediation to the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CmdErrorKind {
+    /// The program could not be found on `PATH`.
+    ProgramNotFound,
+    /// The program exists but could not be executed.
+    PermissionDenied,
+    /// The program ran but exited with a non-zero status.
+    NonZeroExit,
+    /// The program output could not be decoded.
+    InvalidOutput,
+    /// The program didn't finish within its timeout and was killed.
+    Timeout,
+    /// Any other failure.
+    Other,
+}
+
+impl CmdErrorKind {
+    fn remediation(self) -> &'static str {
+        match self {
+            Self::ProgramNotFound => {
+                "Make sure the program is installed and available in your PATH."
+            }
+            Self::PermissionDenied => "Check that the program is executable by the current user.",
+            Self::NonZeroExit => {
+                "Inspect the output above for details, or re-run with `--verbose` to see the full log."
+            }
+            Self::InvalidOutput => "The command produced output that is not valid UTF-8.",
+            Self::Timeout => {
+                "Check your network connection, or whether the command is waiting for input."
+            }
+            Self::Other => "Re-run with `--verbose` to see more details.",
+        }
+    }
+}
+
+impl CmdError {
+    /// Returns the category of this failure.
+    pub fn kind(&self) -> CmdErrorKind {
+        if self.source.downcast_ref::<CmdTimeoutError>().is_some() {
+            return CmdErrorKind::Timeout;
+        }
+        if self.source.downcast_ref::<ProgramNotFoundError>().is_some() {
+            return CmdErrorKind::ProgramNotFound;
+        }
+        if self.stderr.is_some() {
+            return CmdErrorKind::NonZeroExit;
+        }
+        if self.source.downcast_ref::<FromUtf8Error>().is_some() {
+            return CmdErrorKind::InvalidOutput;
+        }
+        // `xshell::Error` doesn't expose its underlying IO error, so we have to rely on its message.
+        if let Some(err) = self.source.downcast_ref::<xshell::Error>() {
+            if err.to_string().starts_with("command not found") {
+                return CmdErrorKind::ProgramNotFound;
+            }
+        }
+        // A missing program is reported as `ProgramNotFoundError` when spawning, so other "not found" IO errors
+        // (e.g., a missing working directory) aren't misreported as a missing program.
+        match self.source.downcast_ref::<io::Error>().map(io::Error::kind) {
+            Some(io::ErrorKind::PermissionDenied) => CmdErrorKind::PermissionDenied,
+            _ => CmdErrorKind::Other,
+        }
+    }
+
+    /// Returns the exit code of the command, if it exited on its own.
+    pub fn code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Returns a user-friendly explanation of the failure, including a suggested remediation.
+    pub fn explain(&self) -> String {
+        let mut explanation = format!("{}", self.source);
+        if let Some(stderr) = self.stderr.as_deref().map(str::trim) {
+            if !stderr.is_empty() {
+                explanation.push_str(&format!("\n\nStderr:\n{stderr}"));
+            }
+        }
+        explanation.push_str(&format!("\n\nHint: {}", self.kind().remediation()));
+        explanation
+    }
+}
+
 impl From<xshell::Error> for CmdError {
     fn from(value: xshell::Error) -> Self {
         Self {
             stderr: None,
+            exit_code: None,
             source: value.into(),
         }
     }
@@ -52,6 +263,7 @@ impl From<io::Error> for CmdError {
     fn from(value: io::Error) -> Self {
         Self {
             stderr: None,
+            exit_code: None,
             source: value.into(),
         }
     }
@@ -61,6 +273,7 @@ impl From<FromUtf8Error> for CmdError {
     fn from(value: FromUtf8Error) -> Self {
         Self {
             stderr: None,
+            exit_code: None,
             source: value.into(),
         }
     }
@@ -72,51 +285,200 @@ impl<'a> Cmd<'a> {
     /// Create a new `Cmd` instance.
     pub fn new(cmd: xshell::Cmd<'a>) -> Self {
         Self {
-            inner: cmd,
+            display: cmd.to_string(),
+            command: cmd.into(),
+            shell: PhantomData,
             force_run: false,
+            dry_run: false,
             piped_std_err: false,
+            current_dir: None,
+            spawn_options: SpawnOptions::default(),
+            retries: 0,
+            retry_backoff: Duration::ZERO,
+            redactor: Redactor::default(),
+            output_format: OutputFormat::default(),
+            spinner_msg: None,
+            allow_failure: false,
+            env_clear: false,
         }
     }
 
+    /// Show a spinner with the given message while the command runs, so that a long quiet command doesn't look
+    /// like a hang. Only applies to [`Cmd::run`] in non-verbose mode, where the output is captured anyway.
+    pub fn with_spinner(mut self, msg: impl Into<String>) -> Self {
+        self.spinner_msg = Some(msg.into());
+        self
+    }
+
     /// Run the command printing the output to the console.
     pub fn with_force_run(mut self) -> Self {
         self.force_run = true;
         self
     }
 
+    /// Only log the command instead of running it. Also enabled for all commands by the global config.
+    pub fn dry_run(mut self) -> Self {
+        self.dry_run = true;
+        self
+    }
+
     pub fn with_piped_std_err(mut self) -> Self {
         self.piped_std_err = true;
         self
     }
 
+    /// Accept a non-zero exit status in [`Cmd::run_expect_stdout_contains`] and
+    /// [`Cmd::run_expect_stderr_contains`], e.g. to check the error message of a command that is expected to fail.
+    pub fn allow_failure(mut self) -> Self {
+        self.allow_failure = true;
+        self
+    }
+
+    /// Kill the command if it doesn't finish within the given duration.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.spawn_options.timeout = Some(timeout);
+        self
+    }
+
+    /// Retry the command up to `count` times if it exits with a non-zero status, waiting `backoff` between attempts.
+    /// Only use it for idempotent commands.
+    pub fn with_retries(mut self, count: u32, backoff: Duration) -> Self {
+        self.retries = count;
+        self.retry_backoff = backoff;
+        self
+    }
+
+    /// Mask the given values, e.g. tokens or private keys passed as arguments, in everything that is logged
+    /// about the command. The command itself still receives the real values.
+    pub fn redact(mut self, patterns: Vec<String>) -> Self {
+        self.redactor.secrets.extend(patterns);
+        self
+    }
+
+    /// Indent logged output by `indent` spaces and wrap it at `wrap` characters (4 and 120 by default).
+    pub fn with_output_format(mut self, indent: usize, wrap: usize) -> Self {
+        self.output_format.indent = indent;
+        self.output_format.wrap = wrap;
+        self
+    }
+
+    /// Log at most `max_len` bytes of stdout and stderr each (1 MiB by default), keeping the beginning and the end
+    /// of the output. Only affects the logs; the output returned to the caller is never truncated.
+    pub fn with_max_logged_output(mut self, max_len: usize) -> Self {
+        self.output_format.max_len = max_len;
+        self
+    }
+
+    /// Call `handler` for each line of captured stdout and stderr as soon as it arrives, e.g. to show
+    /// progress of long-running commands. The full output is still collected and returned.
+    pub fn with_line_handler(mut self, handler: impl FnMut(&str) + Send + 'static) -> Self {
+        self.spawn_options.line_handler =
+            Some(LineHandler(Arc::new(Mutex::new(Box::new(handler)))));
+        self
+    }
+
+    /// Write the given data to the command's stdin.
+    pub fn stdin(mut self, data: Vec<u8>) -> Self {
+        self.spawn_options.stdin = Some(data);
+        self
+    }
+
     /// Set env variables for the command.
     pub fn env<K: AsRef<OsStr>, V: AsRef<OsStr>>(mut self, key: K, value: V) -> Self {
-        self.inner = self.inner.env(key, value);
+        self.command.env(key, value);
+        self
+    }
+
+    /// Set multiple env variables for the command.
+    pub fn envs<I, K, V>(mut self, vars: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<OsStr>,
+        V: AsRef<OsStr>,
+    {
+        self.command.envs(vars);
         self
     }
 
+    /// Remove an env variable, including an inherited one, from the command's environment.
+    pub fn env_remove<K: AsRef<OsStr>>(mut self, key: K) -> Self {
+        self.command.env_remove(key);
+        self
+    }
+
+    /// Start the command with a clean environment instead of inheriting the caller's, e.g. for hermetic builds.
+    /// Only the variables set with [`Cmd::env`] or [`Cmd::envs`] (before or after this call) and the ones set
+    /// on the shell are passed to the command. The program itself is still looked up in the caller's `PATH`
+    /// unless `PATH` is set explicitly.
+    pub fn env_clear(mut self) -> Self {
+        self.env_clear = true;
+        self
+    }
+
+    /// Run the command in the given directory instead of the shell's current one.
+    pub fn current_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+        self.current_dir = Some(dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Check that the program is installed, returning a [`ProgramNotFoundError`] telling the user what to install
+    /// otherwise. Useful to fail early, before a multi-step flow that relies on the program.
+    pub fn require_program(name: &str) -> CmdResult<()> {
+        if find_program(OsStr::new(name), None).is_some() {
+            return Ok(());
+        }
+        Err(CmdError {
+            stderr: None,
+            exit_code: None,
+            source: ProgramNotFoundError {
+                program: name.to_owned(),
+            }
+            .into(),
+        })
+    }
+
     /// Run the command without capturing its output.
     pub fn run(mut self) -> CmdResult<()> {
-        let command_txt = self.inner.to_string();
-        let output = if global_config().verbose || self.force_run {
-            logger::debug(format!("Running: {}", self.inner));
+        let command_txt = self.command_text();
+        if self.is_dry_run() {
+            log_dry_run(&command_txt);
+            return Ok(());
+        }
+        let verbose = global_config().verbose || self.force_run;
+        let piped_std_err = self.piped_std_err;
+        let (retries, retry_backoff) = (self.retries, self.retry_backoff);
+        let output_format = self.output_format;
+        let spinner_msg = self.spinner_msg.take();
+        let (mut command, spawn_options, redactor) = self.into_command();
+
+        let output = if verbose {
+            logger::debug(format!("Running: {}", command_txt));
             logger::new_empty_line();
-            let output = run_low_level_process_command(self.inner.into(), self.piped_std_err)?;
+            let output = retry_on_failure(&command_txt, retries, retry_backoff, || {
+                run_low_level_process_command(
+                    &mut command,
+                    piped_std_err,
+                    &spawn_options,
+                    &command_txt,
+                )
+            })?;
             if let Ok(data) = String::from_utf8(output.stderr.clone()) {
                 if !data.is_empty() {
-                    logger::info(data)
+                    logger::info(redactor.redact(&data))
                 }
             }
             output
         } else {
-            // Command will be logged manually.
-            self.inner.set_quiet(true);
-            // Error will be handled manually.
-            self.inner.set_ignore_status(true);
-            self.inner.output()?
+            // Output is captured and only shown if the command fails.
+            capture_output(&mut command, &spawn_options);
+            let run = || wait_for_output(&mut command, &spawn_options, &command_txt);
+            match &spinner_msg {
+                Some(msg) => retry_with_spinner(msg, retries, retry_backoff, run)?,
+                None => retry_on_failure(&command_txt, retries, retry_backoff, run)?,
+            }
         };
 
-        check_output_status(&command_txt, &output)?;
+        check_output_status(&command_txt, &output, &redactor, output_format)?;
         if global_config().verbose {
             logger::debug(format!("Command completed: {}", command_txt));
         }
@@ -124,32 +486,820 @@ impl<'a> Cmd<'a> {
         Ok(())
     }
 
-    /// Run the command and return its output.
+    /// Run the command and return its output. The command can be run again afterwards.
     pub fn run_with_output(&mut self) -> CmdResult<std::process::Output> {
+        let command_txt = self.command_text();
+        if self.is_dry_run() {
+            log_dry_run(&command_txt);
+            return Ok(dry_run_output());
+        }
+        let verbose = global_config().verbose || self.force_run;
+        if verbose {
+            logger::debug(format!("Running: {}", command_txt));
+            logger::new_empty_line();
+        }
+
+        let mut command = copy_command(&self.command);
+        configure_command(
+            &mut command,
+            self.current_dir.as_deref(),
+            self.env_clear,
+            &self.spawn_options,
+        );
+        capture_output(&mut command, &self.spawn_options);
+        let output = retry_on_failure(&command_txt, self.retries, self.retry_backoff, || {
+            wait_for_output(&mut command, &self.spawn_options, &command_txt)
+        })?;
+
+        if verbose {
+            logger::raw(
+                self.redactor
+                    .redact(&log_output(&output, self.output_format)),
+            );
+            logger::new_empty_line();
+            logger::new_line();
+        }
+
+        Ok(output)
+    }
+
+    /// Run the command, streaming its output to the terminal as it arrives while also capturing it.
+    ///
+    /// Like [`Cmd::run_with_output`], the exit status is not checked. The streamed output is not redacted.
+    pub fn run_with_output_teed(mut self) -> CmdResult<Output> {
+        let command_txt = self.command_text();
+        if self.is_dry_run() {
+            log_dry_run(&command_txt);
+            return Ok(dry_run_output());
+        }
         if global_config().verbose || self.force_run {
-            logger::debug(format!("Running: {}", self.inner));
+            logger::debug(format!("Running: {}", command_txt));
             logger::new_empty_line();
         }
 
-        self.inner.set_ignore_status(true);
-        let output = self.inner.output()?;
+        self.spawn_options.tee = true;
+        let (retries, retry_backoff) = (self.retries, self.retry_backoff);
+        let (mut command, spawn_options, _) = self.into_command();
+        capture_output(&mut command, &spawn_options);
+        retry_on_failure(&command_txt, retries, retry_backoff, || {
+            wait_for_output(&mut command, &spawn_options, &command_txt)
+        })
+    }
+
+    /// Run the command and return its complete output without checking the exit status, for commands where
+    /// a non-zero exit is an expected result, e.g. `diff` or `grep`. The caller inspects `output.status` itself.
+    ///
+    /// Unlike [`Cmd::run_with_output`], nothing is logged even in verbose mode, and retries are not applied.
+    /// An error is only returned if the command can't be run or times out.
+    pub fn run_captured_status(self) -> CmdResult<Output> {
+        let command_txt = self.command_text();
+        if self.is_dry_run() {
+            log_dry_run(&command_txt);
+            return Ok(dry_run_output());
+        }
+        let (mut command, spawn_options, _) = self.into_command();
+        capture_output(&mut command, &spawn_options);
+        wait_for_output(&mut command, &spawn_options, &command_txt)
+    }
+
+    /// Run the command, check that it succeeded and deserialize its stdout as JSON.
+    ///
+    /// Fails in dry-run mode, since the command produces no output then.
+    pub fn output_json<T: DeserializeOwned>(mut self) -> CmdResult<T> {
+        let command_txt = self.command_text();
+        let redactor = self.redactor.clone();
+        let output_format = self.output_format;
+        let output = self.run_with_output()?;
+        check_output_status(&command_txt, &output, &redactor, output_format)?;
+
+        serde_json::from_slice(&output.stdout).map_err(|err| {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            let mut snippet: String = stdout.chars().take(JSON_ERROR_SNIPPET_LEN).collect();
+            if snippet.len() < stdout.len() {
+                snippet.push_str("...");
+            }
+            CmdError {
+                stderr: None,
+                exit_code: None,
+                source: anyhow::Error::new(err).context(format!(
+                    "Failed to parse JSON output of `{command_txt}`, output: {}",
+                    redactor.redact(&snippet)
+                )),
+            }
+        })
+    }
+
+    /// Run the command, check that it succeeded and that its stdout contains `needle`.
+    ///
+    /// The check is skipped in dry-run mode, since the command produces no output then.
+    pub fn run_expect_stdout_contains(self, needle: &str) -> CmdResult<Output> {
+        self.run_expect_output_contains(OutputStream::Stdout, needle)
+    }
+
+    /// Run the command, check that it succeeded and that its stderr contains `needle`.
+    ///
+    /// The check is skipped in dry-run mode, since the command produces no output then.
+    pub fn run_expect_stderr_contains(self, needle: &str) -> CmdResult<Output> {
+        self.run_expect_output_contains(OutputStream::Stderr, needle)
+    }
+
+    fn run_expect_output_contains(
+        mut self,
+        stream: OutputStream,
+        needle: &str,
+    ) -> CmdResult<Output> {
+        let command_txt = self.command_text();
+        let dry_run = self.is_dry_run();
+        let redactor = self.redactor.clone();
+        let output_format = self.output_format;
+        let allow_failure = self.allow_failure;
+        let output = self.run_with_output()?;
+        if dry_run {
+            return Ok(output);
+        }
+        if !allow_failure {
+            check_output_status(&command_txt, &output, &redactor, output_format)?;
+        }
+
+        let actual = match stream {
+            OutputStream::Stdout => String::from_utf8_lossy(&output.stdout),
+            OutputStream::Stderr => String::from_utf8_lossy(&output.stderr),
+        };
+        if actual.contains(needle) {
+            return Ok(output);
+        }
+        Err(CmdError {
+            stderr: None,
+            exit_code: output.status.code(),
+            source: anyhow::anyhow!(
+                "{stream} of `{command_txt}` doesn't contain the expected text\n{}",
+                redactor.redact(&missing_text_diff(needle, &actual))
+            ),
+        })
+    }
+
+    /// Async version of [`Cmd::run`] for callers running inside a tokio runtime.
+    pub fn run_async(self) -> impl Future<Output = CmdResult<()>> + Send {
+        let command_txt = self.command_text();
+        let dry_run = self.is_dry_run();
+        let verbose = global_config().verbose || self.force_run;
+        let piped_std_err = self.piped_std_err;
+        let (retries, retry_backoff) = (self.retries, self.retry_backoff);
+        let output_format = self.output_format;
+        let (mut command, spawn_options, redactor) = self.into_command();
+        if verbose {
+            command.stdout(Stdio::inherit());
+            if piped_std_err {
+                command.stderr(Stdio::piped());
+            } else {
+                command.stderr(Stdio::inherit());
+            }
+        } else {
+            // Output is captured and only shown if the command fails.
+            capture_output(&mut command, &spawn_options);
+        }
+        let mut command = tokio::process::Command::from(command);
+
+        async move {
+            if dry_run {
+                log_dry_run(&command_txt);
+                return Ok(());
+            }
+            if verbose {
+                logger::debug(format!("Running: {}", command_txt));
+                logger::new_empty_line();
+            }
+            let output = retry_on_failure_async(
+                &mut command,
+                &spawn_options,
+                &command_txt,
+                retries,
+                retry_backoff,
+            )
+            .await?;
+            if verbose {
+                if let Ok(data) = String::from_utf8(output.stderr.clone()) {
+                    if !data.is_empty() {
+                        logger::info(redactor.redact(&data))
+                    }
+                }
+            }
+
+            check_output_status(&command_txt, &output, &redactor, output_format)?;
+            if global_config().verbose {
+                logger::debug(format!("Command completed: {}", command_txt));
+            }
+            Ok(())
+        }
+    }
+
+    /// Async version of [`Cmd::run_with_output`] for callers running inside a tokio runtime.
+    pub fn run_with_output_async(self) -> impl Future<Output = CmdResult<Output>> + Send {
+        let command_txt = self.command_text();
+        let dry_run = self.is_dry_run();
+        let verbose = global_config().verbose || self.force_run;
+        let (retries, retry_backoff) = (self.retries, self.retry_backoff);
+        let output_format = self.output_format;
+        let (mut command, spawn_options, redactor) = self.into_command();
+        capture_output(&mut command, &spawn_options);
+        let mut command = tokio::process::Command::from(command);
+
+        async move {
+            if dry_run {
+                log_dry_run(&command_txt);
+                return Ok(dry_run_output());
+            }
+            if verbose {
+                logger::debug(format!("Running: {}", command_txt));
+                logger::new_empty_line();
+            }
+            let output = retry_on_failure_async(
+                &mut command,
+                &spawn_options,
+                &command_txt,
+                retries,
+                retry_backoff,
+            )
+            .await?;
+
+            if verbose {
+                logger::raw(redactor.redact(&log_output(&output, output_format)));
+                logger::new_empty_line();
+                logger::new_line();
+            }
+            Ok(output)
+        }
+    }
 
+    /// Start the command in the background and return a handle to it, e.g. to run a local node while other
+    /// commands execute.
+    ///
+    /// Stdout and stderr are piped and can be taken from the handle; they must be drained if the process
+    /// writes a lot of output, otherwise it blocks once the pipe is full. Timeout and retries are not applied.
+    /// Fails in dry-run mode, since there is no process to return a handle to.
+    pub fn spawn_background(self) -> CmdResult<ProcessHandle> {
+        let command_txt = self.command_text();
+        if self.is_dry_run() {
+            log_dry_run(&command_txt);
+            return Err(CmdError {
+                stderr: None,
+                exit_code: None,
+                source: anyhow::anyhow!(
+                    "Can't spawn `{command_txt}` in the background in dry-run mode"
+                ),
+            });
+        }
         if global_config().verbose || self.force_run {
-            logger::raw(log_output(&output));
+            logger::debug(format!("Spawning in background: {}", command_txt));
+        }
+
+        let (mut command, spawn_options, _) = self.into_command();
+        // The process is killed together with its own children.
+        #[cfg(unix)]
+        std::os::unix::process::CommandExt::process_group(&mut command, 0);
+        capture_output(&mut command, &spawn_options);
+        let (child, stdin_writer) = spawn(&mut command, spawn_options.stdin.as_deref())?;
+        Ok(ProcessHandle {
+            #[cfg(unix)]
+            _interrupt: interrupt::forward_to(child.id()),
+            child,
+            stdin_writer,
+            command_text: command_txt,
+        })
+    }
+
+    /// Pipe the stdout of this command into the stdin of `next`, like `a | b` in a shell. The commands run
+    /// concurrently, so the output is streamed rather than buffered in between.
+    pub fn pipe_to(self, next: Cmd<'a>) -> Pipeline<'a> {
+        Pipeline {
+            stages: vec![self, next],
+        }
+    }
+
+    fn is_dry_run(&self) -> bool {
+        self.dry_run || global_config().dry_run
+    }
+
+    /// Returns the command line for logging, along with the directory if it was overridden.
+    fn command_text(&self) -> String {
+        let text = match &self.current_dir {
+            Some(dir) => format!("{} (in {})", self.display, dir.display()),
+            None => self.display.clone(),
+        };
+        self.redactor.redact(&text)
+    }
+
+    fn into_command(self) -> (Command, SpawnOptions, Redactor) {
+        let mut command = self.command;
+        configure_command(
+            &mut command,
+            self.current_dir.as_deref(),
+            self.env_clear,
+            &self.spawn_options,
+        );
+        (command, self.spawn_options, self.redactor)
+    }
+}
+
+/// Applies the settings of [`Cmd`] that aren't set on the command right away.
+fn configure_command(
+    command: &mut Command,
+    current_dir: Option<&Path>,
+    env_clear: bool,
+    spawn_options: &SpawnOptions,
+) {
+    if let Some(dir) = current_dir {
+        command.current_dir(dir);
+    }
+    if env_clear {
+        // `Command::env_clear()` also drops the variables set so far, so they are set again.
+        let vars: Vec<_> = command
+            .get_envs()
+            .filter_map(|(key, value)| Some((key.to_owned(), value?.to_owned())))
+            .collect();
+        command.env_clear();
+        command.envs(vars);
+    }
+    // Timed out commands are killed together with their own children.
+    #[cfg(unix)]
+    if spawn_options.timeout.is_some() {
+        std::os::unix::process::CommandExt::process_group(command, 0);
+    }
+    if spawn_options.stdin.is_some() {
+        command.stdin(Stdio::piped());
+    }
+}
+
+/// Copies the program, arguments, environment changes and working directory of the command, which is everything
+/// `xshell` and [`Cmd`] set on it. (`Command` isn't `Clone`.) Clearing the environment via [`xshell::Cmd::env_clear`]
+/// can't be read back, unlike [`Cmd::env_clear`].
+fn copy_command(command: &Command) -> Command {
+    let mut copy = Command::new(command.get_program());
+    copy.args(command.get_args());
+    for (key, value) in command.get_envs() {
+        match value {
+            Some(value) => copy.env(key, value),
+            None => copy.env_remove(key),
+        };
+    }
+    if let Some(dir) = command.get_current_dir() {
+        copy.current_dir(dir);
+    }
+    copy
+}
+
+/// Commands with the stdout of each one connected to the stdin of the next one, created by [`Cmd::pipe_to`].
+#[derive(Debug)]
+pub struct Pipeline<'a> {
+    stages: Vec<Cmd<'a>>,
+}
+
+/// A spawned stage of a [`Pipeline`].
+struct RunningStage {
+    command_text: String,
+    redactor: Redactor,
+    output_format: OutputFormat,
+    child: Child,
+    stderr_reader: Option<thread::JoinHandle<Vec<u8>>>,
+}
+
+impl<'a> Pipeline<'a> {
+    /// Append another stage to the pipeline.
+    pub fn pipe_to(mut self, next: Cmd<'a>) -> Self {
+        self.stages.push(next);
+        self
+    }
+
+    /// Run all stages concurrently and return the output of the last one.
+    ///
+    /// Like `set -o pipefail` in a shell, a non-zero exit status of any stage is reported as an error, which
+    /// identifies the first failed stage. Only the input set for the first stage with [`Cmd::stdin`] is used.
+    /// Timeouts, retries and line handlers of the stages are ignored.
+    pub fn run_with_output(self) -> CmdResult<Output> {
+        let command_txt = self.command_text();
+        if self.stages.iter().any(Cmd::is_dry_run) {
+            log_dry_run(&command_txt);
+            return Ok(dry_run_output());
+        }
+        let verbose = global_config().verbose || self.stages.iter().any(|stage| stage.force_run);
+        if verbose {
+            logger::debug(format!("Running: {}", command_txt));
+            logger::new_empty_line();
+        }
+
+        let stage_count = self.stages.len();
+        let mut running: Vec<RunningStage> = Vec::with_capacity(stage_count);
+        let mut stdin_writer = None;
+        for (idx, stage) in self.stages.into_iter().enumerate() {
+            let command_text = stage.command_text();
+            let output_format = stage.output_format;
+            let (mut command, spawn_options, redactor) = stage.into_command();
+            capture_output(&mut command, &spawn_options);
+            if let Some(previous_stdout) =
+                running.last_mut().and_then(|prev| prev.child.stdout.take())
+            {
+                command.stdin(Stdio::from(previous_stdout));
+            }
+
+            let stdin = if idx == 0 {
+                spawn_options.stdin.as_deref()
+            } else {
+                None
+            };
+            let (mut child, writer) = match spawn(&mut command, stdin) {
+                Ok(spawned) => spawned,
+                Err(err) => {
+                    for stage in &mut running {
+                        let _ = stage.child.kill();
+                        let _ = stage.child.wait();
+                    }
+                    return Err(err);
+                }
+            };
+            if idx == 0 {
+                stdin_writer = writer;
+            }
+            let stderr_reader = child
+                .stderr
+                .take()
+                .map(|pipe| spawn_pipe_reader(pipe, None, None));
+            running.push(RunningStage {
+                command_text,
+                redactor,
+                output_format,
+                child,
+                stderr_reader,
+            });
+        }
+        let stdout_reader = running
+            .last_mut()
+            .and_then(|stage| stage.child.stdout.take())
+            .map(|pipe| spawn_pipe_reader(pipe, None, None));
+
+        let mut outputs = Vec::with_capacity(stage_count);
+        for mut stage in running {
+            let status = stage.child.wait()?;
+            let stderr = stage
+                .stderr_reader
+                .take()
+                .map(|reader| reader.join().unwrap_or_default())
+                .unwrap_or_default();
+            let output = Output {
+                status,
+                stdout: vec![],
+                stderr,
+            };
+            outputs.push((stage, output));
+        }
+        join_stdin_writer(stdin_writer)?;
+        let (last_stage, mut output) = outputs.pop().expect("pipeline has at least two stages");
+        output.stdout = stdout_reader
+            .map(|reader| reader.join().unwrap_or_default())
+            .unwrap_or_default();
+        outputs.push((last_stage, output));
+
+        for (idx, (stage, output)) in outputs.iter().enumerate() {
+            check_output_status(
+                &stage.command_text,
+                output,
+                &stage.redactor,
+                stage.output_format,
+            )
+            .map_err(|mut err| {
+                err.source = err.source.context(format!(
+                    "Stage {} of {stage_count} failed in pipeline: {command_txt}",
+                    idx + 1
+                ));
+                err
+            })?;
+        }
+
+        let (last_stage, output) = outputs.pop().expect("pipeline has at least two stages");
+        if verbose {
+            logger::raw(
+                last_stage
+                    .redactor
+                    .redact(&log_output(&output, last_stage.output_format)),
+            );
             logger::new_empty_line();
             logger::new_line();
         }
+        Ok(output)
+    }
 
+    fn command_text(&self) -> String {
+        self.stages
+            .iter()
+            .map(Cmd::command_text)
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+}
+
+/// Handle to a process started by [`Cmd::spawn_background`].
+///
+/// The process and its own children are killed when the handle is dropped, unless the process has already exited.
+/// While the handle exists, Ctrl+C is forwarded to them, since they run in their own process group.
+#[derive(Debug)]
+pub struct ProcessHandle {
+    child: Child,
+    stdin_writer: Option<StdinWriter>,
+    command_text: String,
+    #[cfg(unix)]
+    _interrupt: interrupt::ForwardGuard,
+}
+
+impl ProcessHandle {
+    /// Returns the OS-assigned process ID.
+    pub fn id(&self) -> u32 {
+        self.child.id()
+    }
+
+    /// Takes the stdout pipe of the process. Returns `None` if it was already taken.
+    pub fn take_stdout(&mut self) -> Option<ChildStdout> {
+        self.child.stdout.take()
+    }
+
+    /// Takes the stderr pipe of the process. Returns `None` if it was already taken.
+    pub fn take_stderr(&mut self) -> Option<ChildStderr> {
+        self.child.stderr.take()
+    }
+
+    /// Returns the exit status of the process if it has exited, without blocking.
+    pub fn try_wait(&mut self) -> CmdResult<Option<ExitStatus>> {
+        Ok(self.child.try_wait()?)
+    }
+
+    /// Waits for the process to exit. Pipes that weren't taken are closed first, so that the process doesn't
+    /// block on a full pipe.
+    pub fn wait(&mut self) -> CmdResult<ExitStatus> {
+        drop(self.child.stdout.take());
+        drop(self.child.stderr.take());
+        let status = self.child.wait()?;
+        join_stdin_writer(self.stdin_writer.take())?;
+        Ok(status)
+    }
+
+    /// Kills the process together with its own children and waits for it to exit.
+    /// Does nothing if the process has already exited.
+    pub fn kill(&mut self) -> CmdResult<()> {
+        if self.child.try_wait()?.is_some() {
+            return Ok(());
+        }
+        if global_config().verbose {
+            logger::debug(format!("Killing: {}", self.command_text));
+        }
+        kill_process_group(&mut self.child)?;
+        self.wait()?;
+        Ok(())
+    }
+}
+
+impl Drop for ProcessHandle {
+    fn drop(&mut self) {
+        if let Err(err) = self.kill() {
+            logger::warn(format!(
+                "Failed to kill background process `{}`: {err}",
+                self.command_text
+            ));
+        }
+    }
+}
+
+/// Error returned by [`try_run_all_parallel`] if any of the commands failed.
+#[derive(thiserror::Error, Debug)]
+pub struct ParallelCmdError {
+    /// Failed commands with a short description of the failure, in the original order.
+    pub failures: Vec<(String, String)>,
+    pub total: usize,
+}
+
+impl Display for ParallelCmdError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of {} commands failed:",
+            self.failures.len(),
+            self.total
+        )?;
+        for (command, failure) in &self.failures {
+            write!(f, "\n  {command}: {failure}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Command prepared on the calling thread, so that it can be run on a worker thread.
+struct ParallelJob {
+    command_txt: String,
+    dry_run: bool,
+    verbose: bool,
+    retries: u32,
+    retry_backoff: Duration,
+    output_format: OutputFormat,
+    command: Command,
+    spawn_options: SpawnOptions,
+    redactor: Redactor,
+}
+
+impl ParallelJob {
+    fn new(cmd: Cmd<'_>) -> Self {
+        let command_txt = cmd.command_text();
+        let dry_run = cmd.is_dry_run();
+        let verbose = global_config().verbose || cmd.force_run;
+        let (retries, retry_backoff) = (cmd.retries, cmd.retry_backoff);
+        let output_format = cmd.output_format;
+        let (mut command, spawn_options, redactor) = cmd.into_command();
+        capture_output(&mut command, &spawn_options);
+        Self {
+            command_txt,
+            dry_run,
+            verbose,
+            retries,
+            retry_backoff,
+            output_format,
+            command,
+            spawn_options,
+            redactor,
+        }
+    }
+
+    /// Runs the command without logging its output.
+    fn run(&mut self) -> CmdResult<Output> {
+        if self.dry_run {
+            return Ok(dry_run_output());
+        }
+        let (command, spawn_options, command_txt) =
+            (&mut self.command, &self.spawn_options, &self.command_txt);
+        retry_on_failure(command_txt, self.retries, self.retry_backoff, || {
+            wait_for_output(command, spawn_options, command_txt)
+        })
+    }
+
+    /// Logs the outcome of the command and checks its exit status.
+    fn finish(&self, result: CmdResult<Output>) -> CmdResult<Output> {
+        if self.dry_run {
+            log_dry_run(&self.command_txt);
+            return result;
+        }
+        let output = result?;
+        if self.verbose {
+            logger::debug(format!("Output of: {}", self.command_txt));
+            logger::raw(
+                self.redactor
+                    .redact(&log_output(&output, self.output_format)),
+            );
+            logger::new_empty_line();
+            logger::new_line();
+        }
+        check_output_status(
+            &self.command_txt,
+            &output,
+            &self.redactor,
+            self.output_format,
+        )?;
+        if global_config().verbose {
+            logger::debug(format!("Command completed: {}", self.command_txt));
+        }
         Ok(output)
     }
 }
 
-fn check_output_status(command_text: &str, output: &std::process::Output) -> CmdResult<()> {
+/// Runs the commands on at most `max_concurrency` threads, returning the results in the original order.
+///
+/// Unlike [`Cmd::run_with_output`], a non-zero exit status is reported as an error. All commands are run even if
+/// some of them fail. Output is logged only after all commands have finished, command by command, so that the output
+/// of concurrently running commands doesn't interleave.
+pub fn run_all_parallel(cmds: Vec<Cmd<'_>>, max_concurrency: usize) -> Vec<CmdResult<Output>> {
+    // `Cmd` borrows the shell, which can't be shared between threads, so commands are prepared here.
+    let mut jobs: Vec<_> = cmds.into_iter().map(ParallelJob::new).collect();
+    for job in jobs.iter().filter(|job| job.verbose && !job.dry_run) {
+        logger::debug(format!("Running: {}", job.command_txt));
+    }
+
+    let mut results = Vec::with_capacity(jobs.len());
+    {
+        let queue = Mutex::new(jobs.iter_mut().enumerate());
+        let finished = Mutex::new(&mut results);
+        thread::scope(|scope| {
+            for _ in 0..max_concurrency.max(1) {
+                scope.spawn(|| loop {
+                    let next = queue.lock().unwrap_or_else(PoisonError::into_inner).next();
+                    let Some((idx, job)) = next else {
+                        break;
+                    };
+                    let result = job.run();
+                    finished
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .push((idx, result));
+                });
+            }
+        });
+    }
+    results.sort_by_key(|(idx, _)| *idx);
+
+    jobs.iter()
+        .zip(results)
+        .map(|(job, (_, result))| job.finish(result))
+        .collect()
+}
+
+/// Same as [`run_all_parallel`], but fails with a [`ParallelCmdError`] summarizing all failed commands
+/// if any of them failed.
+pub fn try_run_all_parallel(cmds: Vec<Cmd<'_>>, max_concurrency: usize) -> CmdResult<Vec<Output>> {
+    let command_texts: Vec<_> = cmds.iter().map(Cmd::command_text).collect();
+    let total = command_texts.len();
+    let mut outputs = Vec::with_capacity(total);
+    let mut failures = vec![];
+    for (command_txt, result) in command_texts
+        .into_iter()
+        .zip(run_all_parallel(cmds, max_concurrency))
+    {
+        match result {
+            Ok(output) => outputs.push(output),
+            Err(err) => {
+                let failure = match err.code() {
+                    Some(code) => format!("exit code {code}"),
+                    None => format!("{:#}", err.source),
+                };
+                failures.push((command_txt, failure));
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(outputs)
+    } else {
+        Err(CmdError {
+            stderr: None,
+            exit_code: None,
+            source: ParallelCmdError { failures, total }.into(),
+        })
+    }
+}
+
+/// Output stream of a command checked by the output assertions.
+#[derive(Debug, Clone, Copy)]
+enum OutputStream {
+    Stdout,
+    Stderr,
+}
+
+impl Display for OutputStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Stdout => f.write_str("Stdout"),
+            Self::Stderr => f.write_str("Stderr"),
+        }
+    }
+}
+
+/// Formats the expected and the actual text as a diff, so that a failed output assertion is easy to read.
+fn missing_text_diff(expected: &str, actual: &str) -> String {
+    let mut diff = String::from("--- expected (substring)\n+++ actual\n");
+    for line in expected.lines() {
+        diff.push_str(&format!("-{line}\n"));
+    }
+    if actual.is_empty() {
+        diff.push_str("+<empty>\n");
+    }
+    for line in actual.lines() {
+        diff.push_str(&format!("+{line}\n"));
+    }
+    diff
+}
+
+fn log_dry_run(command_text: &str) {
+    logger::info(format!("Dry run, not executing: {command_text}"));
+}
+
+/// Output returned instead of running the command in dry-run mode.
+fn dry_run_output() -> Output {
+    Output {
+        status: ExitStatus::default(),
+        stdout: vec![],
+        stderr: vec![],
+    }
+}
+
+fn check_output_status(
+    command_text: &str,
+    output: &std::process::Output,
+    redactor: &Redactor,
+    output_format: OutputFormat,
+) -> CmdResult<()> {
     if !output.status.success() {
         logger::new_line();
-        logger::error_note("Command failed to run", &log_output(output));
+        logger::error_note(
+            "Command failed to run",
+            &redactor.redact(&log_output(output, output_format)),
+        );
+        // Invalid UTF-8 shouldn't mask the actual failure.
+        let stderr = String::from_utf8_lossy(&output.stderr);
         return Err(CmdError {
-            stderr: Some(String::from_utf8(output.stderr.clone())?),
+            stderr: Some(redactor.redact(&stderr)),
+            exit_code: output.status.code(),
             source: anyhow::anyhow!("Command failed to run: {}", command_text),
         });
     }
@@ -157,19 +1307,561 @@ fn check_output_status(command_text: &str, output: &std::process::Output) -> Cmd
     Ok(())
 }
 
-fn run_low_level_process_command(mut command: Command, piped_std_err: bool) -> io::Result<Output> {
+/// Runs the command until it exits successfully, retrying up to `retries` times after a non-zero exit.
+fn retry_on_failure(
+    command_text: &str,
+    retries: u32,
+    backoff: Duration,
+    run: impl FnMut() -> CmdResult<Output>,
+) -> CmdResult<Output> {
+    retry_on_failure_with_report(
+        retries,
+        backoff,
+        |report| logger::debug(format!("{report}: {command_text}")),
+        run,
+    )
+}
+
+/// Same as [`retry_on_failure`], but shows a spinner with the message while the command runs. Retries are
+/// reported via the spinner, since logging while it's shown would corrupt the output.
+fn retry_with_spinner(
+    msg: &str,
+    retries: u32,
+    backoff: Duration,
+    run: impl FnMut() -> CmdResult<Output>,
+) -> CmdResult<Output> {
+    let spinner = Spinner::new(msg);
+    let output = retry_on_failure_with_report(
+        retries,
+        backoff,
+        |report| spinner.set_message(format!("{msg} ({report})")),
+        run,
+    );
+    // The spinner is stopped before the output of a failed command is logged.
+    match &output {
+        Ok(output) if output.status.success() => spinner.finish(),
+        _ => spinner.fail(),
+    }
+    output
+}
+
+/// Same as [`retry_on_failure`], but passes the description of each retry to `report` instead of logging it.
+fn retry_on_failure_with_report(
+    retries: u32,
+    backoff: Duration,
+    report: impl Fn(String),
+    mut run: impl FnMut() -> CmdResult<Output>,
+) -> CmdResult<Output> {
+    let reports = RetryReports::new(retries);
+    let mut output = run()?;
+    for attempt in 1..=retries {
+        if output.status.success() {
+            break;
+        }
+        report(reports.failed(attempt, output.status, backoff));
+        thread::sleep(backoff);
+        output = run()?;
+    }
+    if retries > 0 && !output.status.success() {
+        report(reports.failed(retries + 1, output.status, backoff));
+    }
+    Ok(output)
+}
+
+/// Async version of [`retry_on_failure`], running the command via [`wait_for_output_async`].
+async fn retry_on_failure_async(
+    command: &mut tokio::process::Command,
+    spawn_options: &SpawnOptions,
+    command_text: &str,
+    retries: u32,
+    backoff: Duration,
+) -> CmdResult<Output> {
+    let reports = RetryReports::new(retries);
+    let mut output = wait_for_output_async(command, spawn_options, command_text).await?;
+    for attempt in 1..=retries {
+        if output.status.success() {
+            break;
+        }
+        let report = reports.failed(attempt, output.status, backoff);
+        logger::debug(format!("{report}: {command_text}"));
+        tokio::time::sleep(backoff).await;
+        output = wait_for_output_async(command, spawn_options, command_text).await?;
+    }
+    if retries > 0 && !output.status.success() {
+        let report = reports.failed(retries + 1, output.status, backoff);
+        logger::debug(format!("{report}: {command_text}"));
+    }
+    Ok(output)
+}
+
+/// Describes failed attempts of a retried command. Every report is prefixed with a short random ID shared by all
+/// attempts, e.g. `[cmd a1b2] attempt 2/3 failed…`, so that logs of concurrently retried commands can be told apart.
+#[derive(Debug)]
+struct RetryReports {
+    id: String,
+    attempts: u32,
+}
+
+impl RetryReports {
+    fn new(retries: u32) -> Self {
+        // `RandomState` is randomly seeded, so this is a cheap source of randomness for the ID.
+        let id = RandomState::new().build_hasher().finish() as u16;
+        Self {
+            id: format!("{id:04x}"),
+            attempts: retries + 1,
+        }
+    }
+
+    fn failed(&self, attempt: u32, status: ExitStatus, backoff: Duration) -> String {
+        let Self { id, attempts } = self;
+        if attempt < *attempts {
+            format!("[cmd {id}] attempt {attempt}/{attempts} failed with {status}, retrying in {backoff:?}")
+        } else {
+            format!("[cmd {id}] attempt {attempt}/{attempts} failed with {status}, giving up")
+        }
+    }
+}
+
+/// Captures stdout and stderr of the command. Such commands don't read from the terminal either; their stdin is
+/// only connected if input is provided with [`Cmd::stdin`]. Commands that aren't captured inherit the terminal's stdin,
+/// so that interactive tools can prompt the user.
+fn capture_output(command: &mut Command, spawn_options: &SpawnOptions) {
+    command.stdout(Stdio::piped());
+    command.stderr(Stdio::piped());
+    if spawn_options.stdin.is_none() {
+        command.stdin(Stdio::null());
+    }
+}
+
+fn run_low_level_process_command(
+    command: &mut Command,
+    piped_std_err: bool,
+    spawn_options: &SpawnOptions,
+    command_text: &str,
+) -> CmdResult<Output> {
     command.stdout(Stdio::inherit());
     if piped_std_err {
         command.stderr(Stdio::piped());
     } else {
         command.stderr(Stdio::inherit());
     }
-    let child = command.spawn()?;
-    child.wait_with_output()
+    wait_for_output(command, spawn_options, command_text)
 }
 
-fn log_output(output: &std::process::Output) -> String {
-    let (status, stdout, stderr) = get_indented_output(output, 4, 120);
+/// Spawns the command and waits for it to finish. `command_text` is the (redacted) command line used in errors.
+fn wait_for_output(
+    command: &mut Command,
+    spawn_options: &SpawnOptions,
+    command_text: &str,
+) -> CmdResult<Output> {
+    if spawn_options.timeout.is_some() || spawn_options.line_handler.is_some() || spawn_options.tee
+    {
+        return wait_with_pipe_readers(command, spawn_options, command_text);
+    }
+    let (child, stdin_writer) = spawn(command, spawn_options.stdin.as_deref())?;
+    let output = child.wait_with_output()?;
+    join_stdin_writer(stdin_writer)?;
+    Ok(output)
+}
+
+type StdinWriter = thread::JoinHandle<io::Result<()>>;
+
+/// Spawns the command, writing `stdin` to it in the background.
+fn spawn(command: &mut Command, stdin: Option<&[u8]>) -> CmdResult<(Child, Option<StdinWriter>)> {
+    let mut child = command.spawn().map_err(|err| spawn_error(command, err))?;
+
+    let stdin_writer = stdin
+        .zip(child.stdin.take())
+        .map(|(data, pipe)| spawn_stdin_writer(pipe, data.to_vec()));
+    Ok((child, stdin_writer))
+}
+
+fn spawn_error(command: &Command, err: io::Error) -> CmdError {
+    let program = command.get_program().to_string_lossy().into_owned();
+    // `NotFound` is also returned if e.g. the working directory doesn't exist, so the program is looked up
+    // to tell these cases apart.
+    let source = if err.kind() == io::ErrorKind::NotFound
+        && find_program(command.get_program(), command.get_current_dir()).is_none()
+    {
+        anyhow::Error::new(err).context(ProgramNotFoundError { program })
+    } else {
+        anyhow::Error::new(err).context(format!("Failed to run `{program}`"))
+    };
+    CmdError {
+        stderr: None,
+        exit_code: None,
+        source,
+    }
+}
+
+/// Returns the path to the program, looking it up in `PATH` unless it's a path itself. Relative paths
+/// are resolved against `current_dir` if it's set.
+fn find_program(program: &OsStr, current_dir: Option<&Path>) -> Option<PathBuf> {
+    let candidates = |path: PathBuf| {
+        let mut candidates = vec![path.clone()];
+        if cfg!(windows) && path.extension().is_none() {
+            candidates.push(path.with_extension("exe"));
+        }
+        candidates
+    };
+
+    let program = Path::new(program);
+    if program.components().count() > 1 {
+        let program = match current_dir {
+            Some(dir) => dir.join(program),
+            None => program.to_owned(),
+        };
+        return candidates(program).into_iter().find(|path| path.is_file());
+    }
+    let path_var = std::env::var_os("PATH")?;
+    std::env::split_paths(&path_var)
+        .flat_map(|dir| candidates(dir.join(program)))
+        .find(|path| path.is_file())
+}
+
+fn spawn_stdin_writer(mut pipe: ChildStdin, data: Vec<u8>) -> StdinWriter {
+    thread::spawn(move || match pipe.write_all(&data) {
+        // The child may exit without reading all of its input, which is up to the child to report.
+        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+        result => result,
+    })
+}
+
+fn join_stdin_writer(stdin_writer: Option<StdinWriter>) -> CmdResult<()> {
+    if let Some(stdin_writer) = stdin_writer {
+        stdin_writer.join().unwrap_or(Ok(()))?;
+    }
+    Ok(())
+}
+
+/// Spawns the command and waits for it while its pipes are read in the background, passing lines to
+/// the line handler if set. If the command doesn't finish within its timeout, its process group is killed;
+/// stderr captured before the kill is included in the error.
+fn wait_with_pipe_readers(
+    command: &mut Command,
+    spawn_options: &SpawnOptions,
+    command_text: &str,
+) -> CmdResult<Output> {
+    let (mut child, stdin_writer) = spawn(command, spawn_options.stdin.as_deref())?;
+
+    // Pipes are drained in the background, so that the child doesn't block on a full pipe.
+    let line_handler = &spawn_options.line_handler;
+    let tee = |out: Box<dyn Write + Send>| spawn_options.tee.then_some(out);
+    let stdout_reader = child
+        .stdout
+        .take()
+        .map(|pipe| spawn_pipe_reader(pipe, line_handler.clone(), tee(Box::new(io::stdout()))));
+    let stderr_reader = child
+        .stderr
+        .take()
+        .map(|pipe| spawn_pipe_reader(pipe, line_handler.clone(), tee(Box::new(io::stderr()))));
+
+    let status = match spawn_options.timeout {
+        Some(timeout) => {
+            #[cfg(unix)]
+            let _interrupt = interrupt::forward_to(child.id());
+            let deadline = Instant::now() + timeout;
+            loop {
+                if let Some(status) = child.try_wait()? {
+                    break Some(status);
+                }
+                if Instant::now() >= deadline {
+                    kill_process_group(&mut child)?;
+                    child.wait()?;
+                    break None;
+                }
+                thread::sleep(TIMEOUT_POLL_INTERVAL);
+            }
+        }
+        None => Some(child.wait()?),
+    };
+
+    let join_reader = |reader: Option<thread::JoinHandle<Vec<u8>>>| {
+        reader
+            .map(|reader| reader.join().unwrap_or_default())
+            .unwrap_or_default()
+    };
+    let stdout = join_reader(stdout_reader);
+    let stderr = join_reader(stderr_reader);
+    join_stdin_writer(stdin_writer)?;
+
+    let timeout = spawn_options.timeout.unwrap_or_default();
+    finish_output(status, stdout, stderr, timeout, command_text)
+}
+
+/// Builds the command output, or a timeout error if the command was killed (i.e., there is no `status`).
+fn finish_output(
+    status: Option<ExitStatus>,
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+    timeout: Duration,
+    command_text: &str,
+) -> CmdResult<Output> {
+    match status {
+        Some(status) => Ok(Output {
+            status,
+            stdout,
+            stderr,
+        }),
+        None => Err(CmdError {
+            stderr: Some(String::from_utf8_lossy(&stderr).into_owned()),
+            exit_code: None,
+            source: CmdTimeoutError {
+                command: command_text.to_owned(),
+                timeout,
+            }
+            .into(),
+        }),
+    }
+}
+
+/// Async version of [`wait_for_output`]. Pipes are drained in background tasks, so the command
+/// can be killed on timeout without losing its output.
+async fn wait_for_output_async(
+    command: &mut tokio::process::Command,
+    spawn_options: &SpawnOptions,
+    command_text: &str,
+) -> CmdResult<Output> {
+    // Dropping the future (e.g., on cancellation) shouldn't leave the command running.
+    command.kill_on_drop(true);
+    let mut child = command
+        .spawn()
+        .map_err(|err| spawn_error(command.as_std(), err))?;
+
+    let stdin_writer =
+        spawn_options
+            .stdin
+            .clone()
+            .zip(child.stdin.take())
+            .map(|(data, mut pipe)| {
+                tokio::spawn(async move {
+                    match pipe.write_all(&data).await {
+                        // The child may exit without reading all of its input, which is up to the child to report.
+                        Err(err) if err.kind() == io::ErrorKind::BrokenPipe => Ok(()),
+                        result => result,
+                    }
+                })
+            });
+    let line_handler = &spawn_options.line_handler;
+    let stdout_reader = child
+        .stdout
+        .take()
+        .map(|pipe| spawn_pipe_reader_async(pipe, line_handler.clone()));
+    let stderr_reader = child
+        .stderr
+        .take()
+        .map(|pipe| spawn_pipe_reader_async(pipe, line_handler.clone()));
+
+    let status = match spawn_options.timeout {
+        Some(timeout) => {
+            #[cfg(unix)]
+            let _interrupt = child.id().map(interrupt::forward_to);
+            match tokio::time::timeout(timeout, child.wait()).await {
+                Ok(status) => Some(status?),
+                Err(_) => {
+                    kill_process_group_async(&mut child).await?;
+                    None
+                }
+            }
+        }
+        None => Some(child.wait().await?),
+    };
+
+    let join_reader = |reader: Option<tokio::task::JoinHandle<Vec<u8>>>| async move {
+        match reader {
+            Some(reader) => reader.await.unwrap_or_default(),
+            None => vec![],
+        }
+    };
+    let stdout = join_reader(stdout_reader).await;
+    let stderr = join_reader(stderr_reader).await;
+    if let Some(stdin_writer) = stdin_writer {
+        stdin_writer.await.unwrap_or(Ok(()))?;
+    }
+
+    let timeout = spawn_options.timeout.unwrap_or_default();
+    finish_output(status, stdout, stderr, timeout, command_text)
+}
+
+fn spawn_pipe_reader_async(
+    mut pipe: impl AsyncRead + Send + Unpin + 'static,
+    line_handler: Option<LineHandler>,
+) -> tokio::task::JoinHandle<Vec<u8>> {
+    tokio::spawn(async move {
+        let mut buffer = vec![];
+        // Whatever was read before an error is still useful for diagnostics.
+        let Some(line_handler) = line_handler else {
+            let _ = pipe.read_to_end(&mut buffer).await;
+            return buffer;
+        };
+        let mut pipe = tokio::io::BufReader::new(pipe);
+        loop {
+            let line_start = buffer.len();
+            match pipe.read_until(b'\n', &mut buffer).await {
+                Ok(0) | Err(_) => break,
+                Ok(_) => line_handler.call(&buffer[line_start..]),
+            }
+        }
+        buffer
+    })
+}
+
+fn spawn_pipe_reader(
+    mut pipe: impl Read + Send + 'static,
+    line_handler: Option<LineHandler>,
+    mut tee: Option<Box<dyn Write + Send>>,
+) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = vec![];
+        let mut tee = |data: &[u8]| {
+            if let Some(out) = &mut tee {
+                // Failing to write to the terminal shouldn't affect the command.
+                let _ = out.write_all(data).and_then(|()| out.flush());
+            }
+        };
+        // Whatever was read before an error is still useful for diagnostics.
+        let Some(line_handler) = line_handler else {
+            let mut chunk = [0; 8192];
+            loop {
+                match pipe.read(&mut chunk) {
+                    Ok(0) => break,
+                    Ok(len) => {
+                        buffer.extend_from_slice(&chunk[..len]);
+                        tee(&chunk[..len]);
+                    }
+                    Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                    Err(_) => break,
+                }
+            }
+            return buffer;
+        };
+        let mut pipe = BufReader::new(pipe);
+        loop {
+            let line_start = buffer.len();
+            match pipe.read_until(b'\n', &mut buffer) {
+                Ok(0) | Err(_) => break,
+                Ok(_) => {
+                    tee(&buffer[line_start..]);
+                    line_handler.call(&buffer[line_start..]);
+                }
+            }
+        }
+        buffer
+    })
+}
+
+/// Kills the process group led by the child together with the child: the group is asked to terminate with SIGTERM
+/// and killed with SIGKILL if the child doesn't exit within [`KILL_GRACE_PERIOD`]. On platforms without process groups,
+/// only the child is killed.
+fn kill_process_group(child: &mut Child) -> io::Result<()> {
+    #[cfg(unix)]
+    if signal_process_group(child.id(), libc::SIGTERM).is_ok() {
+        let deadline = Instant::now() + KILL_GRACE_PERIOD;
+        while child.try_wait()?.is_none() && Instant::now() < deadline {
+            thread::sleep(TIMEOUT_POLL_INTERVAL);
+        }
+        // Other processes in the group may outlive the leader, so the group is killed in any case.
+        return signal_process_group(child.id(), libc::SIGKILL);
+    }
+    child.kill()
+}
+
+/// Async version of [`kill_process_group`].
+async fn kill_process_group_async(child: &mut tokio::process::Child) -> io::Result<()> {
+    #[cfg(unix)]
+    if let Some(pgid) = child.id() {
+        if signal_process_group(pgid, libc::SIGTERM).is_ok() {
+            let _ = tokio::time::timeout(KILL_GRACE_PERIOD, child.wait()).await;
+            signal_process_group(pgid, libc::SIGKILL)?;
+            child.wait().await?;
+            return Ok(());
+        }
+    }
+    child.kill().await
+}
+
+/// Sends the signal to the process group. A group that no longer exists is not an error.
+#[cfg(unix)]
+fn signal_process_group(pgid: u32, signal: libc::c_int) -> io::Result<()> {
+    // SAFETY: `killpg` has no memory safety preconditions.
+    if unsafe { libc::killpg(pgid as libc::pid_t, signal) } == 0 {
+        return Ok(());
+    }
+    match io::Error::last_os_error() {
+        err if err.raw_os_error() == Some(libc::ESRCH) => Ok(()),
+        err => Err(err),
+    }
+}
+
+/// Commands that may have to be killed together with their children are started in their own process group,
+/// so the terminal doesn't deliver Ctrl+C to them. Instead, SIGINT is forwarded to these groups while they're
+/// registered, after which the current process is interrupted as usual.
+#[cfg(unix)]
+mod interrupt {
+    use std::sync::{
+        atomic::{AtomicI32, Ordering},
+        Once,
+    };
+
+    /// IDs of registered process groups; 0 marks a free slot. The signal handler can neither allocate nor lock,
+    /// hence the fixed-size array.
+    static GROUPS: [AtomicI32; 64] = [const { AtomicI32::new(0) }; 64];
+    static INSTALL_HANDLER: Once = Once::new();
+
+    /// Unregisters the process group when dropped.
+    #[derive(Debug)]
+    pub(super) struct ForwardGuard {
+        slot: Option<usize>,
+    }
+
+    impl Drop for ForwardGuard {
+        fn drop(&mut self) {
+            if let Some(slot) = self.slot {
+                GROUPS[slot].store(0, Ordering::SeqCst);
+            }
+        }
+    }
+
+    /// Forwards SIGINT to the process group until the returned guard is dropped. SIGINT isn't forwarded if the current
+    /// process already handles or ignores it, or if too many groups are registered.
+    pub(super) fn forward_to(pgid: u32) -> ForwardGuard {
+        INSTALL_HANDLER.call_once(|| {
+            let handler = handle_sigint as extern "C" fn(libc::c_int) as libc::sighandler_t;
+            // SAFETY: the handler only calls async-signal-safe functions.
+            unsafe {
+                let previous = libc::signal(libc::SIGINT, handler);
+                if previous != libc::SIG_DFL {
+                    libc::signal(libc::SIGINT, previous);
+                }
+            }
+        });
+        let slot = GROUPS.iter().position(|slot| {
+            slot.compare_exchange(0, pgid as i32, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+        });
+        ForwardGuard { slot }
+    }
+
+    extern "C" fn handle_sigint(_: libc::c_int) {
+        for slot in &GROUPS {
+            let pgid = slot.load(Ordering::SeqCst);
+            if pgid != 0 {
+                // SAFETY: `killpg` is async-signal-safe.
+                unsafe { libc::killpg(pgid, libc::SIGINT) };
+            }
+        }
+        // Terminate the current process as if the handler wasn't installed. The signal is delivered once
+        // the handler returns.
+        // SAFETY: `signal` and `raise` are async-signal-safe.
+        unsafe {
+            libc::signal(libc::SIGINT, libc::SIG_DFL);
+            libc::raise(libc::SIGINT);
+        }
+    }
+}
+
+fn log_output(output: &std::process::Output, format: OutputFormat) -> String {
+    let (status, stdout, stderr) = get_indented_output(output, format);
     log_output_int(status, Some(stdout), Some(stderr))
 }
 
@@ -192,15 +1884,35 @@ fn log_output_int(status: String, stdout: Option<String>, stderr: Option<String>
     format!("{status_header}\n{status}\n{stdout}\n{stderr}")
 }
 
-// Indent output and wrap text.
+/// Keeps the first and the last bytes of the output, so that it takes at most `max_len` bytes
+/// (not counting the marker that replaces the rest).
+fn truncate_output(output: &[u8], max_len: usize) -> Cow<'_, str> {
+    if output.len() <= max_len {
+        return String::from_utf8_lossy(output);
+    }
+    let head_len = max_len / 2;
+    let tail_len = max_len - head_len;
+    let truncated_len = output.len() - max_len;
+    Cow::Owned(format!(
+        "{}\n… {truncated_len} bytes truncated …\n{}",
+        String::from_utf8_lossy(&output[..head_len]),
+        String::from_utf8_lossy(&output[output.len() - tail_len..])
+    ))
+}
+
+// Truncate output, indent it and wrap text.
 fn get_indented_output(
     output: &std::process::Output,
-    indentation: usize,
-    wrap: usize,
+    format: OutputFormat,
 ) -> (String, String, String) {
+    let OutputFormat {
+        indent: indentation,
+        wrap,
+        max_len,
+    } = format;
     let status = output.status.to_string();
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
+    let stdout = truncate_output(&output.stdout, max_len);
+    let stderr = truncate_output(&output.stderr, max_len);
 
     let indent = |s: &str| {
         s.lines()
@@ -209,6 +1921,7 @@ fn get_indented_output(
             .join("\n")
     };
     let wrap_text_to_len = |s: &str| {
+        let wrap = wrap.max(1);
         let mut result = String::new();
 
         for original_line in s.split('\n') {
@@ -218,16 +1931,24 @@ fn get_indented_output(
             }
 
             let mut line = String::new();
+            let mut line_len = 0;
             for word in original_line.split_whitespace() {
-                if line.len() + word.len() + 1 > wrap {
-                    result.push_str(&line);
-                    result.push('\n');
-                    line.clear();
-                }
-                if !line.is_empty() {
-                    line.push(' ');
+                // Words longer than the wrap width are split, so that no line overflows it.
+                let chars: Vec<_> = word.chars().collect();
+                for part in chars.chunks(wrap) {
+                    if line_len > 0 && line_len + part.len() + 1 > wrap {
+                        result.push_str(&line);
+                        result.push('\n');
+                        line.clear();
+                        line_len = 0;
+                    }
+                    if line_len > 0 {
+                        line.push(' ');
+                        line_len += 1;
+                    }
+                    line.extend(part);
+                    line_len += part.len();
                 }
-                line.push_str(word);
             }
             result.push_str(&line);
             result.push('\n');
@@ -242,3 +1963,300 @@ fn get_indented_output(
         indent(&wrap_text_to_len(&stderr)),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Once;
+
+    use super::*;
+    use crate::config::{init_global_config, GlobalConfig};
+
+    fn init_config() {
+        static INIT: Once = Once::new();
+        INIT.call_once(|| {
+            init_global_config(GlobalConfig {
+                verbose: false,
+                chain_name: None,
+                ignore_prerequisites: false,
+                dry_run: false,
+            })
+        });
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn stdin_is_piped_to_command() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        let output = Cmd::new(xshell::cmd!(sh, "cat"))
+            .stdin(b"hello".to_vec())
+            .run_with_output()
+            .unwrap();
+        assert_eq!(output.stdout, b"hello");
+
+        // Captured commands without input don't wait for the terminal.
+        let output = Cmd::new(xshell::cmd!(sh, "cat"))
+            .with_timeout(Duration::from_secs(10))
+            .run_with_output()
+            .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn command_can_be_run_repeatedly() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        let dir = std::env::temp_dir();
+        let mut cmd = Cmd::new(xshell::cmd!(sh, "sh -c 'echo $ZKSTACK_CMD_TEST_VAR; pwd'"))
+            .env("ZKSTACK_CMD_TEST_VAR", "value")
+            .current_dir(&dir);
+        let expected = format!("value\n{}\n", dir.canonicalize().unwrap().display());
+        for _ in 0..2 {
+            let output = cmd.run_with_output().unwrap();
+            assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn timed_out_command_is_killed_with_children() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        let started_at = Instant::now();
+        // The background `sleep` keeps the output pipes open, so the command only returns early
+        // if the whole process group is killed.
+        let err = Cmd::new(xshell::cmd!(
+            sh,
+            "sh -c 'echo partial >&2; sleep 30 & sleep 30'"
+        ))
+        .with_timeout(Duration::from_millis(300))
+        .run()
+        .unwrap_err();
+
+        assert!(started_at.elapsed() < Duration::from_secs(10));
+        assert_eq!(err.kind(), CmdErrorKind::Timeout);
+        assert_eq!(err.code(), None);
+        assert_eq!(err.stderr.as_deref().map(str::trim), Some("partial"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn background_process_is_killed_on_drop() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        let mut handle = Cmd::new(xshell::cmd!(sh, "sh -c 'echo started; sleep 30'"))
+            .spawn_background()
+            .unwrap();
+        let mut stdout = handle.take_stdout().unwrap();
+        let mut line = [0_u8; 8];
+        stdout.read_exact(&mut line).unwrap();
+        assert_eq!(&line, b"started\n");
+        assert!(handle.try_wait().unwrap().is_none());
+
+        let started_at = Instant::now();
+        drop(handle);
+        assert!(started_at.elapsed() < Duration::from_secs(10));
+        // The output pipe is closed once `sleep` is killed as well.
+        assert_eq!(stdout.read(&mut line).unwrap(), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn unread_stdin_is_not_an_error() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        // The command exits without reading its input, so writing it fails with a broken pipe.
+        Cmd::new(xshell::cmd!(sh, "true"))
+            .stdin(vec![0; 10 << 20])
+            .run()
+            .unwrap();
+        let err = Cmd::new(xshell::cmd!(sh, "sh -c 'exit 3'"))
+            .stdin(vec![0; 10 << 20])
+            .run()
+            .unwrap_err();
+        assert_eq!(err.code(), Some(3));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn pipeline_connects_stages() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        let output = Cmd::new(xshell::cmd!(sh, "cat"))
+            .stdin(b"b\na\nc\n".to_vec())
+            .pipe_to(Cmd::new(xshell::cmd!(sh, "sort")))
+            .pipe_to(Cmd::new(xshell::cmd!(sh, "head -n 2")))
+            .run_with_output()
+            .unwrap();
+        assert_eq!(output.stdout, b"a\nb\n");
+
+        // A failed stage is reported even if the last stage succeeds.
+        let err = Cmd::new(xshell::cmd!(sh, "sh -c 'echo partial; exit 3'"))
+            .pipe_to(Cmd::new(xshell::cmd!(sh, "cat")))
+            .run_with_output()
+            .unwrap_err();
+        assert_eq!(err.code(), Some(3));
+        assert!(format!("{:#}", err.source).contains("Stage 1 of 2 failed"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn parallel_failures_are_reported_in_order() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        let cmds = vec![
+            // Finishes last, but is still reported first.
+            Cmd::new(xshell::cmd!(sh, "sh -c 'sleep 0.3; exit 2'")),
+            Cmd::new(xshell::cmd!(sh, "true")),
+            Cmd::new(xshell::cmd!(sh, "sh -c 'exit 3'")),
+        ];
+        let err = try_run_all_parallel(cmds, 3).unwrap_err();
+        let err = err.source.downcast_ref::<ParallelCmdError>().unwrap();
+        assert_eq!(err.total, 3);
+        let failures: Vec<_> = err.failures.iter().map(|(_, failure)| failure).collect();
+        assert_eq!(failures, ["exit code 2", "exit code 3"]);
+
+        let cmds = vec![
+            Cmd::new(xshell::cmd!(sh, "sh -c 'sleep 0.3; echo 1'")),
+            Cmd::new(xshell::cmd!(sh, "echo 2")),
+        ];
+        let outputs = try_run_all_parallel(cmds, 2).unwrap();
+        let stdout: Vec<_> = outputs.iter().map(|output| &output.stdout[..]).collect();
+        assert_eq!(stdout, [b"1\n", b"2\n"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failed_command_is_retried() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        let attempts =
+            std::env::temp_dir().join(format!("zkstack-cmd-retries-{}", std::process::id()));
+        let _ = std::fs::remove_file(&attempts);
+        // Fails on the first two attempts.
+        let script = "echo >> \"$0\"; test $(wc -l < \"$0\") -ge 3";
+
+        Cmd::new(xshell::cmd!(sh, "sh -c {script} {attempts}"))
+            .with_retries(2, Duration::from_millis(10))
+            .run()
+            .unwrap();
+        assert_eq!(std::fs::read(&attempts).unwrap().len(), 3);
+
+        std::fs::remove_file(&attempts).unwrap();
+        let err = Cmd::new(xshell::cmd!(sh, "sh -c {script} {attempts}"))
+            .with_retries(1, Duration::from_millis(10))
+            .run()
+            .unwrap_err();
+        assert_eq!(err.code(), Some(1));
+        assert_eq!(std::fs::read(&attempts).unwrap().len(), 2);
+        std::fs::remove_file(&attempts).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn teed_output_is_captured() {
+        init_config();
+        let sh = xshell::Shell::new().unwrap();
+        let output = Cmd::new(xshell::cmd!(
+            sh,
+            "sh -c 'echo out; echo err >&2; echo more; exit 4'"
+        ))
+        .run_with_output_teed()
+        .unwrap();
+        assert_eq!(output.status.code(), Some(4));
+        assert_eq!(output.stdout, b"out\nmore\n");
+        assert_eq!(output.stderr, b"err\n");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn retry_reports_share_id() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let reports = RetryReports::new(2);
+        let status = ExitStatus::from_raw(1 << 8);
+        let prefix = format!("[cmd {}] ", reports.id);
+        assert_eq!(reports.id.len(), 4);
+        assert_eq!(
+            reports.failed(1, status, Duration::from_secs(1)),
+            format!("{prefix}attempt 1/3 failed with exit status: 1, retrying in 1s")
+        );
+        assert_eq!(
+            reports.failed(3, status, Duration::from_secs(1)),
+            format!("{prefix}attempt 3/3 failed with exit status: 1, giving up")
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8_stderr_keeps_failure() {
+        let output = Command::new("sh")
+            .args(["-c", "printf 'bad \\377 bytes' >&2; exit 3"])
+            .output()
+            .unwrap();
+        let err = check_output_status(
+            "test",
+            &output,
+            &Redactor::default(),
+            OutputFormat::default(),
+        )
+        .unwrap_err();
+
+        assert_eq!(err.kind(), CmdErrorKind::NonZeroExit);
+        assert_eq!(err.code(), Some(3));
+        assert_eq!(err.stderr.as_deref(), Some("bad \u{FFFD} bytes"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn env_clear_drops_inherited_variables() {
+        std::env::set_var("ZKSTACK_CMD_TEST_INHERITED", "1");
+        let sh = xshell::Shell::new().unwrap();
+        let (mut command, _, _) = Cmd::new(xshell::cmd!(sh, "env"))
+            .env("ZKSTACK_CMD_TEST_SET", "1")
+            .env_clear()
+            .into_command();
+        let stdout = String::from_utf8(command.output().unwrap().stdout).unwrap();
+
+        assert!(!stdout.contains("ZKSTACK_CMD_TEST_INHERITED"), "{stdout}");
+        assert!(stdout.contains("ZKSTACK_CMD_TEST_SET=1"), "{stdout}");
+    }
+
+    #[test]
+    fn large_output_is_truncated_in_logs() {
+        let stdout: Vec<u8> = (0..100_000_u32).map(|i| b'a' + (i % 26) as u8).collect();
+        let truncated = truncate_output(&stdout, 1_000);
+        let (head, rest) = truncated.split_once('\n').unwrap();
+        let (marker, tail) = rest.split_once('\n').unwrap();
+        assert_eq!(head.as_bytes(), &stdout[..500]);
+        assert_eq!(marker, "… 99000 bytes truncated …");
+        assert_eq!(tail.as_bytes(), &stdout[stdout.len() - 500..]);
+
+        assert_eq!(truncate_output(&stdout[..1_000], 1_000).len(), 1_000);
+
+        let output = Output {
+            status: ExitStatus::default(),
+            stdout: stdout.clone(),
+            stderr: vec![],
+        };
+        let format = OutputFormat {
+            max_len: 1_000,
+            ..OutputFormat::default()
+        };
+        let (_, logged_stdout, _) = get_indented_output(&output, format);
+        assert!(logged_stdout.contains("99000 bytes truncated"));
+        assert!(logged_stdout.len() < 2_000);
+        assert_eq!(output.stdout, stdout);
+    }
+
+    #[test]
+    fn missing_text_is_reported_as_diff() {
+        assert_eq!(
+            missing_text_diff("bye", "hello\nworld\n"),
+            "--- expected (substring)\n+++ actual\n-bye\n+hello\n+world\n"
+        );
+        assert!(missing_text_diff("bye", "").ends_with("+<empty>\n"));
+    }
+}
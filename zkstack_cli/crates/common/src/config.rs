@@ -17,4 +17,6 @@ pub struct GlobalConfig {
     pub verbose: bool,
     pub chain_name: Option<String>,
     pub ignore_prerequisites: bool,
+    /// Log commands run via [`Cmd`](crate::cmd::Cmd) instead of executing them.
+    pub dry_run: bool,
 }
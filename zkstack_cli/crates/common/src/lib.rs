@@ -22,4 +22,4 @@ pub use prerequisites::{
     PROVER_CLI_PREREQUISITE,
 };
 pub use prompt::{init_prompt_theme, Prompt, PromptConfirm, PromptSelect};
-pub use term::{error, logger, spinner};
+pub use term::{error, logger, spinner, steps};
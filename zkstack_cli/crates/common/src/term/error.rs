@@ -1,19 +1,25 @@
-use crate::logger;
+use crate::{cmd::CmdError, logger};
 
 pub fn log_error(error: anyhow::Error) {
     logger::error(error.to_string());
 
-    if error.chain().count() > 1 {
-        logger::warn(
-            // "Caused by:",
-            error
-                .chain()
-                .skip(1)
-                .enumerate()
-                .map(|(i, cause)| format!("  {i}: {}", cause))
-                .collect::<Vec<_>>()
-                .join("\n"),
-        );
+    // A failed command is explained in detail below, so it's skipped in the chain of causes.
+    let causes: Vec<_> = error
+        .chain()
+        .skip(1)
+        .filter(|cause| !cause.is::<CmdError>())
+        .enumerate()
+        .map(|(i, cause)| format!("  {i}: {}", cause))
+        .collect();
+    if !causes.is_empty() {
+        logger::warn(causes.join("\n"));
+    }
+
+    if let Some(cmd_error) = error
+        .chain()
+        .find_map(|cause| cause.downcast_ref::<CmdError>())
+    {
+        logger::warn(cmd_error.explain());
     }
 
     logger::outro("Failed to run command");
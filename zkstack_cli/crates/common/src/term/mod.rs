@@ -1,3 +1,4 @@
 pub mod error;
 pub mod logger;
 pub mod spinner;
+pub mod steps;
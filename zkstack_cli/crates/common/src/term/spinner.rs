@@ -32,6 +32,11 @@ impl Spinner {
         }
     }
 
+    /// Replace the message shown next to the spinner. The original message is still used when it's finished.
+    pub fn set_message(&self, msg: impl Display) {
+        self.output.set_message(msg);
+    }
+
     /// Manually finish the spinner.
     pub fn finish(self) {
         self.output.stop(format!(
@@ -75,6 +80,13 @@ impl SpinnerOutput {
         }
     }
 
+    fn set_message(&self, msg: impl Display) {
+        match self {
+            SpinnerOutput::Progress(pb) => pb.set_message(msg),
+            SpinnerOutput::Plain() => logger::info(msg),
+        }
+    }
+
     fn stop(self, msg: impl Display) {
         match self {
             SpinnerOutput::Progress(pb) => pb.stop(msg),
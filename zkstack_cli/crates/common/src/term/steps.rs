@@ -0,0 +1,149 @@
+use std::{
+    fmt::{self, Display},
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
+
+use crate::{
+    cmd::{Cmd, CmdResult},
+    logger,
+};
+
+/// Tracks the progress of a flow consisting of a known sequence of named steps, e.g. init, build and deploy.
+///
+/// Each step is announced as `[2/7] Building contracts…`, and errors are annotated with the number and name
+/// of the failed step. A summary with the status and duration of each step is logged once the flow finishes
+/// via [`StepTracker::finish`] or a step fails.
+#[derive(Debug)]
+pub struct StepTracker {
+    steps: Vec<Step>,
+    next: usize,
+}
+
+#[derive(Debug)]
+struct Step {
+    name: String,
+    status: StepStatus,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StepStatus {
+    Pending,
+    Done(Duration),
+    Failed(Duration),
+}
+
+impl StepTracker {
+    pub fn new<S: Into<String>>(names: impl IntoIterator<Item = S>) -> Self {
+        let steps = names
+            .into_iter()
+            .map(|name| Step {
+                name: name.into(),
+                status: StepStatus::Pending,
+            })
+            .collect();
+        Self { steps, next: 0 }
+    }
+
+    /// Run the next step. If it fails, the error is annotated with the step and the summary is logged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if all steps have already been run.
+    pub fn run<T>(&mut self, step: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+        let idx = self.start_step();
+        let started_at = Instant::now();
+        let result = step();
+        self.finish_step(idx, started_at, result.is_ok());
+        result.with_context(|| self.failure_message(idx))
+    }
+
+    /// Run the command as the next step, see [`StepTracker::run`].
+    pub fn run_cmd(&mut self, cmd: Cmd<'_>) -> CmdResult<()> {
+        let idx = self.start_step();
+        let started_at = Instant::now();
+        let result = cmd.run();
+        self.finish_step(idx, started_at, result.is_ok());
+        result.map_err(|mut err| {
+            err.source = err.source.context(self.failure_message(idx));
+            err
+        })
+    }
+
+    /// Log the summary of a successfully completed flow. Steps that weren't run are reported as skipped.
+    pub fn finish(self) {
+        logger::note("Summary", self.summary());
+    }
+
+    fn start_step(&mut self) -> usize {
+        let idx = self.next;
+        let step = self
+            .steps
+            .get(idx)
+            .unwrap_or_else(|| panic!("all {} steps have already been run", self.steps.len()));
+        logger::step(format!("{}…", self.label(idx, &step.name)));
+        self.next += 1;
+        idx
+    }
+
+    fn finish_step(&mut self, idx: usize, started_at: Instant, succeeded: bool) {
+        let elapsed = started_at.elapsed();
+        self.steps[idx].status = if succeeded {
+            StepStatus::Done(elapsed)
+        } else {
+            StepStatus::Failed(elapsed)
+        };
+        if !succeeded {
+            logger::note("Summary", self.summary());
+        }
+    }
+
+    fn failure_message(&self, idx: usize) -> String {
+        format!("{} failed", self.label(idx, &self.steps[idx].name))
+    }
+
+    fn label(&self, idx: usize, name: &str) -> String {
+        format!("[{}/{}] {name}", idx + 1, self.steps.len())
+    }
+
+    fn summary(&self) -> String {
+        self.steps
+            .iter()
+            .enumerate()
+            .map(|(idx, step)| format!("{}: {}", self.label(idx, &step.name), step.status))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl Display for StepStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Pending => write!(f, "skipped"),
+            Self::Done(elapsed) => write!(f, "done in {:.2} secs", elapsed.as_secs_f64()),
+            Self::Failed(elapsed) => write!(f, "failed in {:.2} secs", elapsed.as_secs_f64()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failed_step_is_reported() {
+        let mut tracker = StepTracker::new(["Init", "Build", "Deploy"]);
+        tracker.run(|| Ok(())).unwrap();
+        let err = tracker
+            .run(|| -> anyhow::Result<()> { anyhow::bail!("compiler crashed") })
+            .unwrap_err();
+        assert_eq!(format!("{err:#}"), "[2/3] Build failed: compiler crashed");
+
+        let summary = tracker.summary();
+        let lines: Vec<_> = summary.lines().collect();
+        assert!(lines[0].starts_with("[1/3] Init: done in "), "{summary}");
+        assert!(lines[1].starts_with("[2/3] Build: failed in "), "{summary}");
+        assert_eq!(lines[2], "[3/3] Deploy: skipped");
+    }
+}
@@ -1087,7 +1087,7 @@ _zkstack() {
 
     case "${cmd}" in
         zkstack)
-            opts="-v -h -V --verbose --chain --ignore-prerequisites --help --version autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help"
+            opts="-v -h -V --verbose --chain --ignore-prerequisites --dry-run --help --version autocomplete ecosystem chain dev prover server external-node containers contract-verifier portal explorer consensus update markdown help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 1 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1105,7 +1105,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__autocomplete)
-            opts="-o -v -h --generate --out --verbose --chain --ignore-prerequisites --help"
+            opts="-o -v -h --generate --out --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1135,7 +1135,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help create build-transactions init genesis register-chain deploy-l2-contracts accept-chain-ownership initialize-bridges deploy-consensus-registry deploy-multicall3 deploy-timestamp-asserter deploy-upgrader deploy-paymaster update-token-multiplier-setter help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1153,7 +1153,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__accept__chain__ownership)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1195,7 +1195,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__build__transactions)
-            opts="-o -a -v -h --out --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --l1-rpc-url --verbose --chain --ignore-prerequisites --help"
+            opts="-o -a -v -h --out --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --l1-rpc-url --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1249,7 +1249,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__create)
-            opts="-v -h --chain-name --chain-id --prover-mode --wallet-creation --wallet-path --l1-batch-commit-data-generator-mode --base-token-address --base-token-price-nominator --base-token-price-denominator --set-as-default --legacy-bridge --evm-emulator --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --chain-name --chain-id --prover-mode --wallet-creation --wallet-path --l1-batch-commit-data-generator-mode --base-token-address --base-token-price-nominator --base-token-price-denominator --set-as-default --legacy-bridge --evm-emulator --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1322,7 +1322,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__consensus__registry)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1364,7 +1364,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__l2__contracts)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1406,7 +1406,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__multicall3)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1448,7 +1448,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__paymaster)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1490,7 +1490,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__timestamp__asserter)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1532,7 +1532,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__deploy__upgrader)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1574,7 +1574,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__genesis)
-            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --verbose --chain --ignore-prerequisites --help init-database server help"
+            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --verbose --chain --ignore-prerequisites --dry-run --help init-database server help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1656,7 +1656,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__genesis__init__database)
-            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --verbose --chain --ignore-prerequisites --help"
+            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1682,7 +1682,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__genesis__server)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -1966,7 +1966,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__init)
-            opts="-a -d -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --server-db-url --server-db-name --dont-drop --deploy-paymaster --l1-rpc-url --no-port-reallocation --dev --verbose --chain --ignore-prerequisites --help configs help"
+            opts="-a -d -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --server-db-url --server-db-name --dont-drop --deploy-paymaster --l1-rpc-url --no-port-reallocation --dev --verbose --chain --ignore-prerequisites --dry-run --help configs help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2024,7 +2024,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__init__configs)
-            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --l1-rpc-url --no-port-reallocation --verbose --chain --ignore-prerequisites --help"
+            opts="-d -d -v -h --server-db-url --server-db-name --dev --dont-drop --l1-rpc-url --no-port-reallocation --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2096,7 +2096,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__initialize__bridges)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2138,7 +2138,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__register__chain)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2180,7 +2180,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__chain__update__token__multiplier__setter)
-            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2222,7 +2222,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__consensus)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help set-attester-committee get-attester-committee wait-for-registry help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help set-attester-committee get-attester-committee wait-for-registry help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2240,7 +2240,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__consensus__get__attester__committee)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2328,7 +2328,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__consensus__set__attester__committee)
-            opts="-v -h --from-genesis --from-file --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --from-genesis --from-file --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2350,7 +2350,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__consensus__wait__for__registry)
-            opts="-t -v -h --timeout --poll-interval --verbose --chain --ignore-prerequisites --help"
+            opts="-t -v -h --timeout --poll-interval --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2380,7 +2380,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__containers)
-            opts="-o -v -h --observability --verbose --chain --ignore-prerequisites --help"
+            opts="-o -v -h --observability --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2406,7 +2406,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__contract__verifier)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help build run wait init help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help build run wait init help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2424,7 +2424,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__contract__verifier__build)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2526,7 +2526,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__contract__verifier__init)
-            opts="-v -h --zksolc-version --zkvyper-version --solc-version --era-vm-solc-version --vyper-version --only --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --zksolc-version --zkvyper-version --solc-version --era-vm-solc-version --vyper-version --only --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2564,7 +2564,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__contract__verifier__run)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2582,7 +2582,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__contract__verifier__wait)
-            opts="-t -v -h --timeout --poll-interval --verbose --chain --ignore-prerequisites --help"
+            opts="-t -v -h --timeout --poll-interval --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2612,7 +2612,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help database test clean snapshot lint fmt prover contracts config-writer send-transactions status generate-genesis help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2630,7 +2630,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__clean)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help all containers contracts-cache help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help all containers contracts-cache help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2648,7 +2648,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__clean__all)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2666,7 +2666,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__clean__containers)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2684,7 +2684,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__clean__contracts__cache)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2772,7 +2772,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__config__writer)
-            opts="-p -v -h --path --verbose --chain --ignore-prerequisites --help"
+            opts="-p -v -h --path --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2798,7 +2798,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__contracts)
-            opts="-v -h --l1-contracts --l2-contracts --system-contracts --test-contracts --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --l1-contracts --l2-contracts --system-contracts --test-contracts --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2832,7 +2832,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help check-sqlx-data drop migrate new-migration prepare reset setup help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help check-sqlx-data drop migrate new-migration prepare reset setup help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2850,7 +2850,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__check__sqlx__data)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -2892,7 +2892,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__drop)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3060,7 +3060,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__migrate)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3102,7 +3102,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__new__migration)
-            opts="-v -h --database --name --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --database --name --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3128,7 +3128,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__prepare)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3170,7 +3170,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__reset)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3212,7 +3212,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__database__setup)
-            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --help"
+            opts="-p -c -v -h --prover --prover-url --core --core-url --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3254,7 +3254,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__fmt)
-            opts="-c -v -h --check --verbose --chain --ignore-prerequisites --help rustfmt contract prettier help"
+            opts="-c -v -h --check --verbose --chain --ignore-prerequisites --dry-run --help rustfmt contract prettier help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3272,7 +3272,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__fmt__contract)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3360,7 +3360,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__fmt__prettier)
-            opts="-t -v -h --targets --verbose --chain --ignore-prerequisites --help"
+            opts="-t -v -h --targets --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3386,7 +3386,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__fmt__rustfmt)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -3404,7 +3404,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__generate__genesis)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4024,7 +4024,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__lint)
-            opts="-c -t -v -h --check --targets --verbose --chain --ignore-prerequisites --help"
+            opts="-c -t -v -h --check --targets --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4050,7 +4050,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__prover)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help info insert-batch insert-version help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help info insert-batch insert-version help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4138,7 +4138,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__prover__info)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4156,7 +4156,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__prover__insert__batch)
-            opts="-v -h --number --default --version --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --number --default --version --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4182,7 +4182,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__prover__insert__version)
-            opts="-v -h --default --version --snark-wrapper --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --default --version --snark-wrapper --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4208,7 +4208,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__send__transactions)
-            opts="-v -h --file --private-key --l1-rpc-url --confirmations --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --file --private-key --l1-rpc-url --confirmations --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4242,7 +4242,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__snapshot)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help create help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help create help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4260,7 +4260,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__snapshot__create)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4320,7 +4320,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__status)
-            opts="-u -v -h --url --verbose --chain --ignore-prerequisites --help ports help"
+            opts="-u -v -h --url --verbose --chain --ignore-prerequisites --dry-run --help ports help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4388,7 +4388,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__status__ports)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4406,7 +4406,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help integration fees revert recovery upgrade build rust l1-contracts prover wallet loadtest help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4424,7 +4424,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__build)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4442,7 +4442,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__fees)
-            opts="-n -v -h --no-deps --no-kill --verbose --chain --ignore-prerequisites --help"
+            opts="-n -v -h --no-deps --no-kill --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4642,7 +4642,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__integration)
-            opts="-e -n -t -v -h --external-node --no-deps --test-pattern --verbose --chain --ignore-prerequisites --help"
+            opts="-e -n -t -v -h --external-node --no-deps --test-pattern --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4668,7 +4668,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__l1__contracts)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4686,7 +4686,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__loadtest)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4704,7 +4704,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__prover)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4722,7 +4722,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__recovery)
-            opts="-s -n -v -h --snapshot --no-deps --no-kill --verbose --chain --ignore-prerequisites --help"
+            opts="-s -n -v -h --snapshot --no-deps --no-kill --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4740,7 +4740,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__revert)
-            opts="-e -n -v -h --enable-consensus --external-node --no-deps --no-kill --verbose --chain --ignore-prerequisites --help"
+            opts="-e -n -v -h --enable-consensus --external-node --no-deps --no-kill --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4758,7 +4758,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__rust)
-            opts="-v -h --options --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --options --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4780,7 +4780,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__upgrade)
-            opts="-n -v -h --no-deps --verbose --chain --ignore-prerequisites --help"
+            opts="-n -v -h --no-deps --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4798,7 +4798,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__dev__test__wallet)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 4 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4816,7 +4816,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help create build-transactions init change-default-chain setup-observability help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help create build-transactions init change-default-chain setup-observability help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4834,7 +4834,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem__build__transactions)
-            opts="-o -a -v -h --sender --l1-rpc-url --out --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-o -a -v -h --sender --l1-rpc-url --out --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4892,7 +4892,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem__change__default__chain)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help [NAME]"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help [NAME]"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -4910,7 +4910,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem__create)
-            opts="-v -h --ecosystem-name --l1-network --link-to-code --chain-name --chain-id --prover-mode --wallet-creation --wallet-path --l1-batch-commit-data-generator-mode --base-token-address --base-token-price-nominator --base-token-price-denominator --set-as-default --legacy-bridge --evm-emulator --start-containers --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --ecosystem-name --l1-network --link-to-code --chain-name --chain-id --prover-mode --wallet-creation --wallet-path --l1-batch-commit-data-generator-mode --base-token-address --base-token-price-nominator --base-token-price-denominator --set-as-default --legacy-bridge --evm-emulator --start-containers --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5100,7 +5100,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem__init)
-            opts="-a -d -o -v -h --deploy-erc20 --deploy-ecosystem --ecosystem-contracts-path --l1-rpc-url --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --deploy-paymaster --server-db-url --server-db-name --dont-drop --ecosystem-only --dev --observability --no-port-reallocation --verbose --chain --ignore-prerequisites --help"
+            opts="-a -d -o -v -h --deploy-erc20 --deploy-ecosystem --ecosystem-contracts-path --l1-rpc-url --verify --verifier --verifier-url --verifier-api-key --resume --additional-args --deploy-paymaster --server-db-url --server-db-name --dont-drop --ecosystem-only --dev --observability --no-port-reallocation --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5178,7 +5178,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__ecosystem__setup__observability)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5196,7 +5196,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__explorer)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help init run-backend run help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help init run-backend run help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5284,7 +5284,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__explorer__init)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5302,7 +5302,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__explorer__run)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5320,7 +5320,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__explorer__run__backend)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5338,7 +5338,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__external__node)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help configs init build run wait help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help configs init build run wait help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5356,7 +5356,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__external__node__build)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5374,7 +5374,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__external__node__configs)
-            opts="-u -v -h --db-url --db-name --l1-rpc-url --use-default --verbose --chain --ignore-prerequisites --help"
+            opts="-u -v -h --db-url --db-name --l1-rpc-url --use-default --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5502,7 +5502,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__external__node__init)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5520,7 +5520,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__external__node__run)
-            opts="-a -v -h --reinit --components --enable-consensus --additional-args --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --reinit --components --enable-consensus --additional-args --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -5554,7 +5554,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__external__node__wait)
-            opts="-t -v -h --timeout --poll-interval --verbose --chain --ignore-prerequisites --help"
+            opts="-t -v -h --timeout --poll-interval --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7012,7 +7012,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__markdown)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7030,7 +7030,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__portal)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7048,7 +7048,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__prover)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help init setup-keys run init-bellman-cuda compressor-keys help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help init setup-keys run init-bellman-cuda compressor-keys help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7066,7 +7066,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__prover__compressor__keys)
-            opts="-v -h --path --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --path --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7186,7 +7186,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__prover__init)
-            opts="-u -d -v -h --dev --proof-store-dir --bucket-base-url --credentials-file --bucket-name --location --project-id --shall-save-to-public-bucket --public-store-dir --public-bucket-base-url --public-credentials-file --public-bucket-name --public-location --public-project-id --clone --bellman-cuda-dir --bellman-cuda --setup-compressor-key --path --region --mode --setup-keys --setup-database --prover-db-url --prover-db-name --use-default --dont-drop --cloud-type --verbose --chain --ignore-prerequisites --help"
+            opts="-u -d -v -h --dev --proof-store-dir --bucket-base-url --credentials-file --bucket-name --location --project-id --shall-save-to-public-bucket --public-store-dir --public-bucket-base-url --public-credentials-file --public-bucket-name --public-location --public-project-id --clone --bellman-cuda-dir --bellman-cuda --setup-compressor-key --path --region --mode --setup-keys --setup-database --prover-db-url --prover-db-name --use-default --dont-drop --cloud-type --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7316,7 +7316,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__prover__init__bellman__cuda)
-            opts="-v -h --clone --bellman-cuda-dir --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --clone --bellman-cuda-dir --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7338,7 +7338,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__prover__run)
-            opts="-v -h --component --round --threads --max-allocation --witness-vector-generator-count --max-allocation --docker --tag --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --component --round --threads --max-allocation --witness-vector-generator-count --max-allocation --docker --tag --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7388,7 +7388,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__prover__setup__keys)
-            opts="-v -h --region --mode --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --region --mode --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7414,7 +7414,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__server)
-            opts="-a -v -h --components --genesis --additional-args --uring --verbose --chain --ignore-prerequisites --help build run wait help"
+            opts="-a -v -h --components --genesis --additional-args --uring --verbose --chain --ignore-prerequisites --dry-run --help build run wait help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7444,7 +7444,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__server__build)
-            opts="-v -h --verbose --chain --ignore-prerequisites --help"
+            opts="-v -h --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7532,7 +7532,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__server__run)
-            opts="-a -v -h --components --genesis --additional-args --uring --verbose --chain --ignore-prerequisites --help"
+            opts="-a -v -h --components --genesis --additional-args --uring --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7562,7 +7562,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__server__wait)
-            opts="-t -v -h --timeout --poll-interval --verbose --chain --ignore-prerequisites --help"
+            opts="-t -v -h --timeout --poll-interval --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 3 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -7592,7 +7592,7 @@ _zkstack() {
             return 0
             ;;
         zkstack__update)
-            opts="-c -v -h --only-config --verbose --chain --ignore-prerequisites --help"
+            opts="-c -v -h --only-config --verbose --chain --ignore-prerequisites --dry-run --help"
             if [[ ${cur} == -* || ${COMP_CWORD} -eq 2 ]] ; then
                 COMPREPLY=( $(compgen -W "${opts}" -- "${cur}") )
                 return 0
@@ -95,6 +95,12 @@ struct ZkStackGlobalArgs {
     /// Ignores prerequisites checks
     #[clap(long, global = true)]
     ignore_prerequisites: bool,
+    /// Prints commands instead of running them; other actions such as file writes still happen
+    ///
+    /// Only affects external commands run through `common::cmd::Cmd`. Commands spawned directly
+    /// (e.g. by some `dev` subcommands) and file writes are still executed.
+    #[clap(long, global = true)]
+    dry_run: bool,
 }
 
 #[tokio::main]
@@ -172,6 +178,7 @@ fn init_global_config_inner(shell: &Shell, zkstack_args: &ZkStackGlobalArgs) ->
         verbose: zkstack_args.verbose,
         chain_name: zkstack_args.chain.clone(),
         ignore_prerequisites: zkstack_args.ignore_prerequisites,
+        dry_run: zkstack_args.dry_run,
     });
     Ok(())
 }